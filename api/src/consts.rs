@@ -38,6 +38,9 @@ pub const ONE_DAY_SLOTS: u64 = 24 * ONE_HOUR_SLOTS;
 /// The number of slots in one week.
 pub const ONE_WEEK_SLOTS: u64 = 7 * ONE_DAY_SLOTS;
 
+/// The default number of slots in a Sprint round.
+pub const ROUND_SLOTS: u64 = 150;
+
 /// The number of slots for breather between rounds.
 pub const INTERMISSION_SLOTS: u64 = 35;
 
@@ -50,6 +53,9 @@ pub const AUTOMATION: &[u8] = b"automation";
 /// The seed of the board account PDA.
 pub const BOARD: &[u8] = b"board";
 
+/// The id of the default Sprint table, whose accounts keep their original addresses.
+pub const DEFAULT_TABLE_ID: u8 = 0;
+
 /// The seed of the config account PDA.
 pub const CONFIG: &[u8] = b"config";
 
@@ -68,6 +74,30 @@ pub const STAKE: &[u8] = b"stake";
 /// The seed of the garage account PDA.
 pub const GARAGE: &[u8] = b"garage";
 
+/// The seed of the garage automation account PDA.
+pub const GARAGE_AUTOMATION: &[u8] = b"garage_automation";
+
+/// The seed of the garage transfer account PDA.
+pub const GARAGE_TRANSFER: &[u8] = b"garage_transfer";
+
+/// The seed of the garage forward account PDA.
+pub const GARAGE_FORWARD: &[u8] = b"garage_forward";
+
+/// The seed of the garage vault token account PDA.
+pub const GARAGE_VAULT: &[u8] = b"garage_vault";
+
+/// The seed of the referral code account PDA.
+pub const REFERRAL_CODE: &[u8] = b"referral_code";
+
+/// The seed of the referral ledger account PDA.
+pub const REFERRAL_LEDGER: &[u8] = b"referral_ledger";
+
+/// The seed of the pending change account PDA.
+pub const PENDING_CHANGE: &[u8] = b"pending_change";
+
+/// The seed of the role transfer account PDA.
+pub const ROLE_TRANSFER: &[u8] = b"role_transfer";
+
 /// The seed of the round account PDA.
 pub const ROUND: &[u8] = b"round";
 
@@ -88,6 +118,10 @@ pub const SPLIT_ADDRESS: Pubkey = pubkey!("SpLiT11111111111111111111111111111111
 /// The address to indicate automation is permissionless.
 pub const EXECUTOR_ADDRESS: Pubkey = pubkey!("executor11111111111111111111111111111111112");
 
+/// The authority of the house Garage, used as the referrer when a user has no referral code.
+/// Referral fees for house-referred deposits are routed to the team.
+pub const HOUSE_ADDRESS: Pubkey = pubkey!("house11111111111111111111111111111111111112");
+
 /// Denominator for fee calculations (basis points).
 pub const DENOMINATOR_BPS: u64 = 10_000;
 
@@ -120,6 +154,13 @@ pub const FUEL_UP_POOL_BPS: u64 = 2800;
 /// Referral fee from Fuel Up (10% of deposit).
 pub const FUEL_UP_REF_BPS: u64 = 1000;
 
+/// Number of referrer levels paid on Fuel Up.
+pub const REFERRAL_LEVELS: usize = 3;
+
+/// Default referral fee per level from Fuel Up (6% / 3% / 1% of deposit, summing to
+/// FUEL_UP_REF_BPS). The live split is `ProtocolParams::ref_level_bps`.
+pub const FUEL_UP_REF_LEVEL_BPS: [u64; REFERRAL_LEVELS] = [600, 300, 100];
+
 /// Team fee from Fuel Up (7% of deposit).
 pub const FUEL_UP_TEAM_BPS: u64 = 700;
 
@@ -145,10 +186,13 @@ pub const COLLECT_POOL_BPS: u64 = 800;
 // Whale Tax Schedule (applied AFTER base collect tax)
 // ============================================================================
 
-/// Whale tax thresholds in BPS (% of total Garage TVL).
+/// Number of whale tax tiers.
+pub const WHALE_TAX_TIERS: usize = 10;
+
+/// Whale tax thresholds in BPS (% of total outstanding Garage obligation).
 /// Schedule: <1%=0%, >=1%=5%, >=2%=10%, >=3%=15%, >=4%=20%, >=5%=25%,
 ///           >=6%=30%, >=7%=35%, >=8%=40%, >=9%=45%, >=10%=50%
-pub const WHALE_TAX_THRESHOLD_BPS: [u64; 10] = [
+pub const WHALE_TAX_THRESHOLD_BPS: [u64; WHALE_TAX_TIERS] = [
     100,  // 1% of TVL
     200,  // 2% of TVL
     300,  // 3% of TVL
@@ -162,7 +206,7 @@ pub const WHALE_TAX_THRESHOLD_BPS: [u64; 10] = [
 ];
 
 /// Whale tax rates corresponding to each threshold (5% increments).
-pub const WHALE_TAX_RATE_BPS: [u64; 10] = [
+pub const WHALE_TAX_RATE_BPS: [u64; WHALE_TAX_TIERS] = [
     500,  // 5% whale tax at >=1% TVL
     1000, // 10% whale tax at >=2% TVL
     1500, // 15% whale tax at >=3% TVL
@@ -199,6 +243,85 @@ pub const MAX_PAYOUT_MULT: u64 = 365;
 /// Minimum deposit amount (10 FUEL).
 pub const MIN_DEPOSIT: u64 = ONE_FUEL * 10;
 
+/// Minimum length of a referral code.
+pub const REFERRAL_CODE_MIN_LEN: usize = 3;
+
+/// Maximum length of a referral code.
+pub const REFERRAL_CODE_MAX_LEN: usize = 16;
+
+// ============================================================================
+// Protocol Parameter Bounds (enforced by ProposeChange and ExecuteChange)
+// ============================================================================
+
+/// The current layout version of the protocol parameters in Config.
+/// Bump on every ProtocolParams layout change so MigrateConfig rewrites stale parameters.
+/// 1: Garage economics, 2: Sprint buyback fee, 3: claim expiry, 4: referral level split.
+pub const CONFIG_VERSION: u64 = 4;
+
+/// Maximum daily yield rate (5% per day).
+pub const MAX_DAILY_RATE_BPS: u64 = 500;
+
+/// Minimum max payout multiplier (100% of total deposited).
+pub const MIN_PAYOUT_MULT: u64 = 100;
+
+/// Maximum max payout multiplier (1000% of total deposited).
+pub const MAX_PAYOUT_MULT_LIMIT: u64 = 1000;
+
+/// Maximum Collect tax (50% of withdrawn amount).
+pub const MAX_COLLECT_TAX_BPS: u64 = 5000;
+
+/// Maximum whale tax rate (90%).
+pub const MAX_WHALE_TAX_BPS: u64 = 9000;
+
+/// Maximum Sprint team fee (10% of wagered SOL).
+pub const MAX_SPRINT_TEAM_FEE_BPS: u64 = 1000;
+
+/// Maximum total Sprint protocol fee, team plus buyback (20% of wagered SOL).
+pub const MAX_SPRINT_PROTOCOL_FEE_BPS: u64 = 2000;
+
+/// Maximum checkpoint fee (0.001 SOL).
+pub const MAX_CHECKPOINT_FEE: u64 = 1_000_000;
+
+/// Minimum Sprint round length (10 seconds).
+pub const MIN_ROUND_SLOTS: u64 = 25;
+
+/// Maximum Sprint round length (one hour).
+pub const MAX_ROUND_SLOTS: u64 = ONE_HOUR_SLOTS;
+
+/// Maximum intermission between Sprint rounds (ten minutes).
+pub const MAX_INTERMISSION_SLOTS: u64 = 10 * ONE_MINUTE_SLOTS;
+
+/// Minimum window for claiming round rewards (one hour).
+pub const MIN_CLAIM_EXPIRY_SLOTS: u64 = ONE_HOUR_SLOTS;
+
+/// Maximum window for claiming round rewards (one week).
+pub const MAX_CLAIM_EXPIRY_SLOTS: u64 = ONE_WEEK_SLOTS;
+
+/// Minimum timelock delay for admin changes, in seconds (one day).
+pub const MIN_TIMELOCK_DELAY: u64 = ONE_DAY as u64;
+
+/// Maximum timelock delay for admin changes, in seconds (30 days).
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * ONE_DAY as u64;
+
+/// Pause flag for Sprint deploys (manual and automated).
+pub const PAUSE_DEPLOY: u64 = 1 << 0;
+
+/// Pause flag for Sprint round resets.
+pub const PAUSE_RESET: u64 = 1 << 1;
+
+/// Pause flag for Garage deposits (FuelUp, Boost and Stash).
+pub const PAUSE_GARAGE_DEPOSIT: u64 = 1 << 2;
+
+/// Pause flag for Garage withdrawals (Collect and Harvest).
+pub const PAUSE_GARAGE_WITHDRAW: u64 = 1 << 3;
+
+/// Pause flag for reward claims (ClaimSOL, ClaimORE, ClaimWallet and Stash).
+pub const PAUSE_CLAIM: u64 = 1 << 4;
+
+/// Every pause flag.
+pub const PAUSE_ALL: u64 =
+    PAUSE_DEPLOY | PAUSE_RESET | PAUSE_GARAGE_DEPOSIT | PAUSE_GARAGE_WITHDRAW | PAUSE_CLAIM;
+
 /// The address of the boost reserve token account.
 /// TODO: Set this to the Speedway boost reserve address before mainnet deployment
 pub const BOOST_RESERVE_TOKEN: Pubkey = pubkey!("Gce36ZUsBDJsoLrfCBxUB5Sfq2DsGunofStvxFx6rBiD");
@@ -212,7 +335,7 @@ pub const COMPOUND_FEE_PER_TRANSACTION: u64 = 7_000;
 /// The fee paid to the admin for each transaction.
 pub const ADMIN_FEE: u64 = 100; // 1%
 
-/// The address to receive the admin fee.
+/// The initial fee collector, stored in Config at initialization.
 /// TODO: Set this to the Speedway team fee collector address before mainnet deployment
 pub const ADMIN_FEE_COLLECTOR: Pubkey = pubkey!("DyB4Kv6V613gp2LWQTq1dwDYHGKuUEoDHnCouGUtxFiX");
 
@@ -223,7 +346,7 @@ pub const SWAP_PROGRAM: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNy
 /// TODO: Set this to the Speedway var address before mainnet deployment
 pub const VAR_ADDRESS: Pubkey = pubkey!("BWCaDY96Xe4WkFq1M7UiCCRcChsJ3p51L5KrGzhxgm2E");
 
-/// The address which can call the bury and wrap instructions.
+/// The initial treasury operator, which can call the buyback, wrap and liq instructions.
 /// TODO: Set this to the Speedway bury authority before mainnet deployment
 pub const BURY_AUTHORITY: Pubkey = pubkey!("HNWhK5f8RMWBqcA7mXJPaxdTPGrha3rrqUrri7HSKb3T");
 
@@ -242,6 +365,21 @@ pub const BURY_AUTHORITY: Pubkey = pubkey!("HNWhK5f8RMWBqcA7mXJPaxdTPGrha3rrqUrr
 ///
 /// Returns the whale tax rate in basis points.
 pub fn calculate_whale_tax_bps(user_balance: u64, total_tvl: u64) -> u64 {
+    calculate_whale_tax_bps_with(
+        user_balance,
+        total_tvl,
+        &WHALE_TAX_THRESHOLD_BPS,
+        &WHALE_TAX_RATE_BPS,
+    )
+}
+
+/// Calculate whale tax in BPS against a given threshold and rate schedule.
+pub fn calculate_whale_tax_bps_with(
+    user_balance: u64,
+    total_tvl: u64,
+    thresholds: &[u64; WHALE_TAX_TIERS],
+    rates: &[u64; WHALE_TAX_TIERS],
+) -> u64 {
     // Prevent division by zero - no whale tax if TVL is 0
     if total_tvl == 0 {
         return 0;
@@ -256,9 +394,9 @@ pub fn calculate_whale_tax_bps(user_balance: u64, total_tvl: u64) -> u64 {
 
     // Find the appropriate tax rate based on thresholds
     // Iterate backwards through thresholds to find highest matching tier
-    for i in (0..WHALE_TAX_THRESHOLD_BPS.len()).rev() {
-        if user_percentage_bps >= WHALE_TAX_THRESHOLD_BPS[i] {
-            return WHALE_TAX_RATE_BPS[i];
+    for i in (0..thresholds.len()).rev() {
+        if user_percentage_bps >= thresholds[i] {
+            return rates[i];
         }
     }
 
@@ -519,6 +657,11 @@ mod tests {
             DENOMINATOR_BPS,
             "Fuel Up fee split should equal 100%"
         );
+        assert_eq!(
+            FUEL_UP_REF_LEVEL_BPS.iter().sum::<u64>(),
+            FUEL_UP_REF_BPS,
+            "Referral levels should sum to the referral fee"
+        );
 
         // Boost: 2% team + 3% pool = 5% total
         assert_eq!(
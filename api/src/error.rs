@@ -12,6 +12,27 @@ pub enum OreError {
     #[error("Invalid executor")]
     InvalidExecutor = 2,
 
+    #[error("Protocol parameters out of bounds")]
+    InvalidParams = 3,
+
+    #[error("Timelock active (use ProposeChange)")]
+    TimelockActive = 4,
+
+    #[error("Timelock has not elapsed")]
+    TimelockNotElapsed = 5,
+
+    #[error("ETA is earlier than the timelock delay allows")]
+    TimelockTooShort = 6,
+
+    #[error("This operation is paused")]
+    Paused = 7,
+
+    #[error("Invalid squares (0-24, each listed once with a non-zero amount)")]
+    InvalidSquares = 8,
+
+    #[error("Config role is not set (migrate Config first)")]
+    RoleNotSet = 9,
+
     // ============================================================================
     // Garage Errors
     // ============================================================================
@@ -31,11 +52,29 @@ pub enum OreError {
     #[error("Referrer has no Garage account")]
     ReferrerNoGarage = 104,
 
-    #[error("Insufficient pool balance")]
+    #[error("Insufficient pool balance (backstop cap reached)")]
     InsufficientPoolBalance = 105,
 
     #[error("Garage account required (use FuelUp first)")]
     GarageRequired = 106,
+
+    #[error("Amount exceeds available rewards")]
+    AmountExceedsAvailable = 107,
+
+    #[error("Garage automation policy does not allow this action yet")]
+    AutomationNotReady = 108,
+
+    #[error("Automation balance too low to pay executor fee or migration rent")]
+    AutomationUnderfunded = 109,
+
+    #[error("Recipient already has a Garage account")]
+    GarageAlreadyExists = 110,
+
+    #[error("Invalid referral code (3-16 alphanumeric characters)")]
+    InvalidReferralCode = 111,
+
+    #[error("Referral code already claimed")]
+    ReferralCodeTaken = 112,
 }
 
 error!(OreError);
@@ -1,17 +1,32 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::state::ProtocolParams;
+
 pub enum OreEvent {
     Reset = 0,
     Bury = 1,
     Deploy = 2,
     Liq = 3,
+    AutomationStopped = 4,
     // Garage events
     FuelUp = 10,
     Boost = 11,
     Collect = 12,
     Stash = 13,
     ClaimWallet = 14,
+    Harvest = 15,
+    NewCycle = 16,
+    GarageTransfer = 17,
+    ReferralReward = 18,
+    // Admin events
+    ParamsUpdated = 50,
+    ChangeProposed = 51,
+    ChangeExecuted = 52,
+    ChangeCancelled = 53,
+    RoleTransfer = 54,
+    PauseUpdated = 55,
+    TableUpdated = 56,
 }
 
 #[repr(C)]
@@ -94,7 +109,7 @@ pub struct DeployEvent {
     /// The authority of the deployer.
     pub authority: Pubkey,
 
-    /// The amount of SOL deployed per square.
+    /// The amount of SOL deployed per square (0 for DeployMulti, see `amounts`).
     pub amount: u64,
 
     /// The mask of the squares deployed to.
@@ -112,6 +127,37 @@ pub struct DeployEvent {
     /// The total number of squares deployed to.
     pub total_squares: u64,
 
+    /// The total amount of SOL deployed.
+    pub total_amount: u64,
+
+    /// The amount of SOL deployed to each square.
+    pub amounts: [u64; 25],
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct AutomationStoppedEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the automation.
+    pub authority: Pubkey,
+
+    /// Why the automation was closed (see AutomationStopReason).
+    pub reason: u64,
+
+    /// The number of rounds played since the automation was last configured.
+    pub rounds_played: u64,
+
+    /// The balance refunded to the authority.
+    pub refund: u64,
+
+    /// The round id.
+    pub round_id: u64,
+
     /// The timestamp of the event.
     pub ts: i64,
 }
@@ -135,6 +181,7 @@ pub struct LiqEvent {
 event!(ResetEvent);
 event!(BuryEvent);
 event!(DeployEvent);
+event!(AutomationStoppedEvent);
 event!(LiqEvent);
 
 // ============================================================================
@@ -150,22 +197,28 @@ pub struct FuelUpEvent {
     /// The authority (user) who deposited.
     pub authority: Pubkey,
 
-    /// The gross deposit amount (before tax).
+    /// The gross deposit amount.
     pub gross_amount: u64,
 
-    /// The net amount credited to Garage (after 10% tax).
+    /// The amount credited to Garage (100% of gross).
     pub net_amount: u64,
 
-    /// The team fee (2% of gross).
+    /// The amount burned (55% of gross).
+    pub burn_amount: u64,
+
+    /// The team fee (7% of gross, 17% for house referrals).
     pub team_fee: u64,
 
-    /// The referral fee (5% of gross).
+    /// The referral fee credited to the referrer (10% of gross, 0% for house referrals).
     pub ref_fee: u64,
 
-    /// The pool fee (3% of gross).
+    /// The pool fee (28% of gross, plus any redirected referral fee).
     pub pool_fee: u64,
 
-    /// The referrer who received the referral fee.
+    /// The referral fee redirected to the pool (referrer Garage closed or exhausted).
+    pub ref_redirected: u64,
+
+    /// The stored referrer of the user's Garage.
     pub referrer: Pubkey,
 
     /// The new total_deposited in user's Garage.
@@ -196,6 +249,12 @@ pub struct BoostEvent {
     /// The boost tax (5% of gross).
     pub tax: u64,
 
+    /// Boost tax portion sent to team (2% of gross).
+    pub team_fee: u64,
+
+    /// Boost tax portion retained for the pool (3% of gross, not minted).
+    pub pool_fee: u64,
+
     /// The new total_deposited in user's Garage.
     pub new_total_deposited: u64,
 
@@ -224,15 +283,24 @@ pub struct CollectEvent {
     /// The base collect tax (10% of gross).
     pub base_tax: u64,
 
+    /// Base tax portion sent to team (2% of gross).
+    pub base_tax_team: u64,
+
+    /// Base tax portion sent to pool (8% of gross).
+    pub base_tax_pool: u64,
+
     /// The whale tax (0-50% based on TVL %).
     pub whale_tax: u64,
 
-    /// Whale tax portion sent to team (10% of whale_tax).
+    /// Whale tax portion sent to team (30% of whale_tax).
     pub whale_tax_team: u64,
 
-    /// Whale tax portion sent to pool (90% of whale_tax).
+    /// Whale tax portion sent to pool (70% of whale_tax).
     pub whale_tax_pool: u64,
 
+    /// FUEL minted as a backstop because the Garage vault was short.
+    pub backstop_minted: u64,
+
     /// The new total_claimed in user's Garage.
     pub new_total_claimed: u64,
 
@@ -300,3 +368,334 @@ pub struct ClaimWalletEvent {
 }
 
 event!(ClaimWalletEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct HarvestEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority (user) who harvested.
+    pub authority: Pubkey,
+
+    /// The gross rewards harvested (boosted + collected).
+    pub gross_amount: u64,
+
+    /// The gross rewards compounded (before boost tax).
+    pub boost_amount: u64,
+
+    /// The net amount added to deposits (after boost tax).
+    pub boost_net: u64,
+
+    /// The boost tax (5% of boost_amount).
+    pub boost_tax: u64,
+
+    /// Boost tax portion sent to team (2% of boost_amount).
+    pub boost_team_fee: u64,
+
+    /// Boost tax portion retained for the pool (3% of boost_amount, not minted).
+    pub boost_pool_fee: u64,
+
+    /// The gross rewards withdrawn (before taxes).
+    pub collect_amount: u64,
+
+    /// The net amount received (after all taxes).
+    pub collect_net: u64,
+
+    /// The base collect tax (10% of collect_amount).
+    pub base_tax: u64,
+
+    /// Base tax portion sent to team (2% of collect_amount).
+    pub base_tax_team: u64,
+
+    /// Base tax portion sent to pool (8% of collect_amount).
+    pub base_tax_pool: u64,
+
+    /// The whale tax (0-50% based on TVL %).
+    pub whale_tax: u64,
+
+    /// Whale tax portion sent to team (30% of whale_tax).
+    pub whale_tax_team: u64,
+
+    /// Whale tax portion sent to pool (70% of whale_tax).
+    pub whale_tax_pool: u64,
+
+    /// FUEL minted as a backstop because the Garage vault was short.
+    pub backstop_minted: u64,
+
+    /// The new total_deposited in user's Garage.
+    pub new_total_deposited: u64,
+
+    /// The new max_payout in user's Garage.
+    pub new_max_payout: u64,
+
+    /// The new total_claimed in user's Garage.
+    pub new_total_claimed: u64,
+
+    /// Whether the Garage is now exhausted.
+    pub is_exhausted: u8,
+
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(HarvestEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct NewCycleEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority (user) whose Garage restarted.
+    pub authority: Pubkey,
+
+    /// The number of completed cycles (including the one just archived).
+    pub cycle: u64,
+
+    /// The total_deposited of the archived cycle.
+    pub cycle_deposited: u64,
+
+    /// The total_claimed of the archived cycle.
+    pub cycle_claimed: u64,
+
+    /// The new lifetime_deposited in user's Garage.
+    pub lifetime_deposited: u64,
+
+    /// The new lifetime_claimed in user's Garage.
+    pub lifetime_claimed: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(NewCycleEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct GarageTransferEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The previous owner of the Garage.
+    pub from: Pubkey,
+
+    /// The new owner of the Garage.
+    pub to: Pubkey,
+
+    /// The referrer carried over to the new Garage.
+    pub referrer: Pubkey,
+
+    /// The total_deposited moved.
+    pub total_deposited: u64,
+
+    /// The total_claimed moved.
+    pub total_claimed: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(GarageTransferEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ReferralRewardEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The user whose deposit generated the reward.
+    pub referee: Pubkey,
+
+    /// The referrer credited at this level.
+    pub referrer: Pubkey,
+
+    /// The level of the referrer chain (1 = direct referrer).
+    pub level: u64,
+
+    /// The FUEL credited to the referrer's Garage.
+    pub amount: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(ReferralRewardEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ParamsUpdatedEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The admin who updated the parameters.
+    pub authority: Pubkey,
+
+    /// The Config layout version.
+    pub version: u64,
+
+    /// The previous protocol parameters.
+    pub old_params: ProtocolParams,
+
+    /// The new protocol parameters.
+    pub new_params: ProtocolParams,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(ParamsUpdatedEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ChangeProposedEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The id of the pending change.
+    pub id: u64,
+
+    /// The kind of change (see ChangeKind).
+    pub kind: u64,
+
+    /// The admin who proposed the change.
+    pub authority: Pubkey,
+
+    /// The new value (BackstopCap and TimelockDelay changes, or the table id of Var and Table
+    /// changes).
+    pub value: u64,
+
+    /// The new protocol parameters (Params changes).
+    pub params: ProtocolParams,
+
+    /// The new minimum SOL per square of the table (Table changes).
+    pub min_deploy: u64,
+
+    /// The new round length override of the table (Table changes).
+    pub round_slots: u64,
+
+    /// The new intermission override of the table (Table changes).
+    pub intermission_slots: u64,
+
+    /// Earliest timestamp the change may be executed.
+    pub eta: i64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(ChangeProposedEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ChangeExecutedEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The id of the pending change.
+    pub id: u64,
+
+    /// The kind of change (see ChangeKind).
+    pub kind: u64,
+
+    /// The account that executed the change.
+    pub executor: Pubkey,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(ChangeExecutedEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ChangeCancelledEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The id of the pending change.
+    pub id: u64,
+
+    /// The kind of change (see ChangeKind).
+    pub kind: u64,
+
+    /// The admin who cancelled the change.
+    pub authority: Pubkey,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(ChangeCancelledEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct RoleTransferEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The role transferred (see Role).
+    pub role: u64,
+
+    /// The previous holder of the role.
+    pub from: Pubkey,
+
+    /// The new holder of the role.
+    pub to: Pubkey,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(RoleTransferEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct PauseUpdatedEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The pauser who updated the mask.
+    pub authority: Pubkey,
+
+    /// The previous pause bitmask.
+    pub old_mask: u64,
+
+    /// The new pause bitmask.
+    pub new_mask: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(PauseUpdatedEvent);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct TableUpdatedEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The table that was opened or updated.
+    pub table_id: u64,
+
+    /// The admin who updated the table.
+    pub authority: Pubkey,
+
+    /// Minimum SOL per square, in lamports.
+    pub min_deploy: u64,
+
+    /// Round length override, in slots.
+    pub round_slots: u64,
+
+    /// Intermission override, in slots.
+    pub intermission_slots: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+event!(TableUpdatedEvent);
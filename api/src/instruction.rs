@@ -1,5 +1,11 @@
 use steel::*;
 
+use crate::{
+    consts::{REFERRAL_LEVELS, WHALE_TAX_TIERS},
+    error::OreError,
+    state::ProtocolParams,
+};
+
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
 pub enum OreInstruction {
@@ -10,6 +16,7 @@ pub enum OreInstruction {
     ClaimORE = 4,
     Close = 5,
     Deploy = 6,
+    DeployMulti = 57,
     Log = 8,
     Reset = 9,
     ReloadSOL = 21,
@@ -26,12 +33,37 @@ pub enum OreInstruction {
     Collect = 32,
     Stash = 33,
     ClaimWallet = 34,
+    Harvest = 35,
+    AutomateGarage = 36,
+    ProposeGarageTransfer = 37,
+    AcceptGarageTransfer = 38,
+    ClaimReferralCode = 39,
+    ReleaseReferralCode = 40,
+    TransferReferralCode = 41,
+    FuelUpWithCode = 42,
 
     // Admin
     Buyback = 13,
     Bury = 24,
     Wrap = 14,
-    SetAdmin = 15,
+    // DEPRECATED: replaced by ProposeRoleTransfer/AcceptRoleTransfer
+    // SetAdmin = 15,
+    // DEPRECATED: replaced by ProposeChange with ChangeKind::BackstopCap and ChangeKind::Params
+    // SetBackstopCap = 26,
+    // SetParams = 27,
+    SetFeeCollector = 16,
+    // DEPRECATED: replaced by ProposeChange with ChangeKind::Params
+    // SetFeeRate = 17,
+    ProposeChange = 50,
+    ExecuteChange = 51,
+    CancelChange = 52,
+    ProposeRoleTransfer = 53,
+    AcceptRoleTransfer = 54,
+    SetPause = 55,
+    SetTable = 56,
+    MigrateBoard = 58,
+    MigrateConfig = 59,
+    InitGarageVault = 60,
     NewVar = 19,
     Liq = 25,
     Initialize = 100,
@@ -46,6 +78,11 @@ pub struct Automate {
     pub mask: [u8; 8],
     pub strategy: u8,
     pub reload: [u8; 8],
+    pub round_budget: [u8; 8],
+    pub daily_budget: [u8; 8],
+    pub stop_loss: [u8; 8],
+    pub max_rounds: [u8; 8],
+    pub expires_at_slot: [u8; 8],
 }
 
 #[repr(C)]
@@ -61,6 +98,46 @@ pub struct ClaimORE {}
 pub struct Deploy {
     pub amount: [u8; 8],
     pub squares: [u8; 4],
+    pub table_id: u8,
+}
+
+/// DeployMulti: Deploy a different amount to each listed square.
+/// The header is followed by one `DeploySquare` entry per square.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct DeployMulti {
+    pub table_id: u8,
+}
+
+/// A (square, amount) pair trailing a DeployMulti instruction.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct DeploySquare {
+    pub square: u8,
+    pub amount: [u8; 8],
+}
+
+impl DeployMulti {
+    /// Parses the trailing entries into per-square amounts.
+    /// Squares must be in range and listed at most once, each with a non-zero amount.
+    pub fn amounts(entries: &[u8]) -> Result<[u64; 25], ProgramError> {
+        let size = std::mem::size_of::<DeploySquare>();
+        if entries.is_empty() || entries.len() % size != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let mut amounts = [0u64; 25];
+        for entry in entries.chunks_exact(size) {
+            let entry = bytemuck::try_from_bytes::<DeploySquare>(entry)
+                .or(Err(ProgramError::InvalidInstructionData))?;
+            let square = entry.square as usize;
+            let amount = u64::from_le_bytes(entry.amount);
+            if square > 24 || amount == 0 || amounts[square] > 0 {
+                return Err(OreError::InvalidSquares.into());
+            }
+            amounts[square] = amount;
+        }
+        Ok(amounts)
+    }
 }
 
 #[repr(C)]
@@ -69,7 +146,9 @@ pub struct Log {}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Reset {}
+pub struct Reset {
+    pub table_id: u8,
+}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -92,22 +171,166 @@ pub struct Uncommit {
     pub amount: [u8; 8],
 }
 
+/// SetParams: The protocol parameters carried by a ProposeChange of kind Params.
+/// Every parameter is bounds-checked when the change is proposed and again when executed.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetAdmin {
-    pub admin: [u8; 32],
+pub struct SetParams {
+    pub daily_rate_bps: [u8; 8],
+    pub max_payout_mult: [u8; 8],
+    pub collect_tax_bps: [u8; 8],
+    pub whale_tax_threshold_bps: [[u8; 8]; WHALE_TAX_TIERS],
+    pub whale_tax_rate_bps: [[u8; 8]; WHALE_TAX_TIERS],
+    pub sprint_team_fee_bps: [u8; 8],
+    pub sprint_buyback_fee_bps: [u8; 8],
+    pub checkpoint_fee: [u8; 8],
+    pub round_slots: [u8; 8],
+    pub intermission_slots: [u8; 8],
+    pub claim_expiry_slots: [u8; 8],
+    pub ref_level_bps: [[u8; 8]; REFERRAL_LEVELS],
 }
 
+impl SetParams {
+    pub fn params(&self) -> ProtocolParams {
+        ProtocolParams {
+            daily_rate_bps: u64::from_le_bytes(self.daily_rate_bps),
+            max_payout_mult: u64::from_le_bytes(self.max_payout_mult),
+            collect_tax_bps: u64::from_le_bytes(self.collect_tax_bps),
+            whale_tax_threshold_bps: self.whale_tax_threshold_bps.map(u64::from_le_bytes),
+            whale_tax_rate_bps: self.whale_tax_rate_bps.map(u64::from_le_bytes),
+            sprint_team_fee_bps: u64::from_le_bytes(self.sprint_team_fee_bps),
+            sprint_buyback_fee_bps: u64::from_le_bytes(self.sprint_buyback_fee_bps),
+            checkpoint_fee: u64::from_le_bytes(self.checkpoint_fee),
+            round_slots: u64::from_le_bytes(self.round_slots),
+            intermission_slots: u64::from_le_bytes(self.intermission_slots),
+            claim_expiry_slots: u64::from_le_bytes(self.claim_expiry_slots),
+            ref_level_bps: self.ref_level_bps.map(u64::from_le_bytes),
+        }
+    }
+}
+
+impl From<ProtocolParams> for SetParams {
+    fn from(params: ProtocolParams) -> Self {
+        Self {
+            daily_rate_bps: params.daily_rate_bps.to_le_bytes(),
+            max_payout_mult: params.max_payout_mult.to_le_bytes(),
+            collect_tax_bps: params.collect_tax_bps.to_le_bytes(),
+            whale_tax_threshold_bps: params.whale_tax_threshold_bps.map(u64::to_le_bytes),
+            whale_tax_rate_bps: params.whale_tax_rate_bps.map(u64::to_le_bytes),
+            sprint_team_fee_bps: params.sprint_team_fee_bps.to_le_bytes(),
+            sprint_buyback_fee_bps: params.sprint_buyback_fee_bps.to_le_bytes(),
+            checkpoint_fee: params.checkpoint_fee.to_le_bytes(),
+            round_slots: params.round_slots.to_le_bytes(),
+            intermission_slots: params.intermission_slots.to_le_bytes(),
+            claim_expiry_slots: params.claim_expiry_slots.to_le_bytes(),
+            ref_level_bps: params.ref_level_bps.map(u64::to_le_bytes),
+        }
+    }
+}
+
+/// ProposeChange: Queue a timelocked change to Config or a table.
+/// Only callable by the admin. The ETA must be at least `Config.timelock_delay` away.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetFeeCollector {
-    pub fee_collector: [u8; 32],
+pub struct ProposeChange {
+    /// The kind of change (see ChangeKind).
+    pub kind: u8,
+
+    /// The new value (BackstopCap and TimelockDelay changes, or the table id of Var and Table
+    /// changes).
+    pub value: [u8; 8],
+
+    /// Earliest timestamp the change may be executed.
+    pub eta: [u8; 8],
+
+    /// The new protocol parameters (Params changes).
+    pub params: SetParams,
+
+    /// The new minimum SOL per square of the table (Table changes).
+    pub min_deploy: [u8; 8],
+
+    /// The new round length override of the table (Table changes).
+    pub round_slots: [u8; 8],
+
+    /// The new intermission override of the table (Table changes).
+    pub intermission_slots: [u8; 8],
 }
 
+/// ExecuteChange: Apply a pending change once its ETA has passed. Callable by anyone.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct SetFeeRate {
-    pub fee_rate: [u8; 8],
+pub struct ExecuteChange {}
+
+/// CancelChange: Discard a pending change. Only callable by the admin.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CancelChange {}
+
+/// ProposeRoleTransfer: Propose handing a Config role to a new address.
+/// Callable by the current holder or the admin. Passing Pubkey::default() as the recipient cancels.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ProposeRoleTransfer {
+    /// The role to transfer (see Role).
+    pub role: u8,
+}
+
+/// AcceptRoleTransfer: Accept a pending role transfer as the recipient.
+/// Only allowed once the Config timelock delay has elapsed since the proposal.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct AcceptRoleTransfer {
+    /// The role to accept (see Role).
+    pub role: u8,
+}
+
+/// SetTable: Open a Sprint table. Only callable by the admin.
+/// Zero round or intermission lengths fall back to the Config defaults. Open tables are
+/// updated with a ProposeChange of kind Table.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetTable {
+    pub table_id: u8,
+    pub min_deploy: [u8; 8],
+    pub round_slots: [u8; 8],
+    pub intermission_slots: [u8; 8],
+}
+
+/// MigrateBoard: Grow a table's Board to the current layout. Permissionless; the signer
+/// pays the rent.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct MigrateBoard {
+    pub table_id: u8,
+}
+
+/// MigrateConfig: Grow Config to the current layout, resetting outdated protocol parameters
+/// to their defaults. Permissionless; the signer pays the rent.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct MigrateConfig {}
+
+/// InitGarageVault: Create the Garage vault token account if it does not exist yet.
+/// Permissionless; the signer pays the rent.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct InitGarageVault {}
+
+/// SetPause: Replace the Config pause bitmask. Only callable by the pauser.
+/// Not timelocked, so the pauser can halt the program immediately.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetPause {
+    /// The new pause bitmask (see the PAUSE_* flags).
+    pub mask: [u8; 8],
+}
+
+/// SetFeeCollector: Propose a new fee collector.
+/// Shorthand for ProposeRoleTransfer with Role::FeeCollector; the new collector must accept.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetFeeCollector {
+    pub fee_collector: [u8; 32],
 }
 
 #[repr(C)]
@@ -152,11 +375,15 @@ pub struct ReloadSOL {}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Checkpoint {}
+pub struct Checkpoint {
+    pub table_id: u8,
+}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Close {}
+pub struct Close {
+    pub table_id: u8,
+}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -164,6 +391,7 @@ pub struct NewVar {
     pub id: [u8; 8],
     pub commit: [u8; 32],
     pub samples: [u8; 8],
+    pub table_id: u8,
 }
 
 #[repr(C)]
@@ -206,13 +434,31 @@ pub struct FuelUp {
 /// Adds rewards to total_deposited, increasing max_payout.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Boost {}
+pub struct Boost {
+    /// Amount of accrued FUEL to compound (in drops, 0 = all available).
+    pub amount: [u8; 8],
+}
 
 /// Collect: Withdraw accrued rewards from Garage.
-/// Subject to 10% collect tax.
+/// Subject to 10% collect tax. Paid from the Garage vault, then a capped backstop mint.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Collect {
+    /// Amount of accrued FUEL to withdraw (in drops, 0 = all available).
+    pub amount: [u8; 8],
+}
+
+/// Harvest: Compound part of accrued rewards and withdraw the rest in one instruction.
+/// The boosted part pays the boost tax; the withdrawn part pays collect + whale tax.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Collect {}
+pub struct Harvest {
+    /// Share of the harvested amount to compound, in basis points (0-10000).
+    pub boost_bps: [u8; 8],
+
+    /// Amount of accrued FUEL to harvest (in drops, 0 = all available).
+    pub amount: [u8; 8],
+}
 
 /// Stash: Send Sprint FUEL rewards directly to Garage.
 /// 0% fee - frictionless path from Sprint rewards to Garage.
@@ -226,6 +472,66 @@ pub struct Stash {}
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct ClaimWallet {}
 
+/// AutomateGarage: Open, update or close a Garage automation.
+/// Passing Pubkey::default() as the executor closes the automation.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct AutomateGarage {
+    /// Lamports to add to the executor fee reserve.
+    pub deposit: [u8; 8],
+
+    /// Lamports paid to the executor per execution.
+    pub fee: [u8; 8],
+
+    /// Minimum seconds between executions.
+    pub interval: [u8; 8],
+
+    /// Number of boosts before a collect (BoostThenCollect strategy).
+    pub boost_count: [u8; 8],
+
+    /// The automation strategy.
+    pub strategy: u8,
+}
+
+/// ProposeGarageTransfer: Propose moving the signer's Garage to a new owner.
+/// Passing Pubkey::default() as the recipient cancels a pending proposal.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ProposeGarageTransfer {}
+
+/// AcceptGarageTransfer: Accept a pending Garage transfer as the recipient.
+/// Moves the position into the recipient's Garage PDA and closes the old one.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct AcceptGarageTransfer {}
+
+/// ClaimReferralCode: Claim a vanity referral code pointing to the signer's Garage.
+/// The code must be normalized (see ReferralCode::normalize).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimReferralCode {
+    /// Normalized referral code (uppercase alphanumerics, zero-padded).
+    pub code: [u8; 16],
+}
+
+/// ReleaseReferralCode: Release a referral code owned by the signer and reclaim its rent.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ReleaseReferralCode {}
+
+/// TransferReferralCode: Transfer a referral code to another Garage owner.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct TransferReferralCode {}
+
+/// FuelUpWithCode: Deposit FUEL tokens into Garage, resolving the referrer from a referral code.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct FuelUpWithCode {
+    /// Amount of FUEL to deposit (in drops).
+    pub amount: [u8; 8],
+}
+
 // ============================================================================
 // Admin Instructions
 // ============================================================================
@@ -244,12 +550,23 @@ instruction!(OreInstruction, ClaimSOL);
 instruction!(OreInstruction, ClaimORE);
 instruction!(OreInstruction, ReloadSOL);
 instruction!(OreInstruction, Deploy);
+instruction!(OreInstruction, DeployMulti);
 instruction!(OreInstruction, Log);
 instruction!(OreInstruction, Wrap);
 instruction!(OreInstruction, Buyback);
 instruction!(OreInstruction, Bury);
 instruction!(OreInstruction, Reset);
-instruction!(OreInstruction, SetAdmin);
+instruction!(OreInstruction, SetFeeCollector);
+instruction!(OreInstruction, ProposeChange);
+instruction!(OreInstruction, ExecuteChange);
+instruction!(OreInstruction, CancelChange);
+instruction!(OreInstruction, ProposeRoleTransfer);
+instruction!(OreInstruction, AcceptRoleTransfer);
+instruction!(OreInstruction, SetPause);
+instruction!(OreInstruction, SetTable);
+instruction!(OreInstruction, MigrateBoard);
+instruction!(OreInstruction, MigrateConfig);
+instruction!(OreInstruction, InitGarageVault);
 // DEPRECATED: ORE Staking (replaced by Garage system)
 // instruction!(OreInstruction, Deposit);
 // instruction!(OreInstruction, Withdraw);
@@ -264,3 +581,11 @@ instruction!(OreInstruction, Boost);
 instruction!(OreInstruction, Collect);
 instruction!(OreInstruction, Stash);
 instruction!(OreInstruction, ClaimWallet);
+instruction!(OreInstruction, Harvest);
+instruction!(OreInstruction, AutomateGarage);
+instruction!(OreInstruction, ProposeGarageTransfer);
+instruction!(OreInstruction, AcceptGarageTransfer);
+instruction!(OreInstruction, ClaimReferralCode);
+instruction!(OreInstruction, ReleaseReferralCode);
+instruction!(OreInstruction, TransferReferralCode);
+instruction!(OreInstruction, FuelUpWithCode);
@@ -26,6 +26,20 @@ declare_id!("9gKS98fsEpt6nqRy78SEUppvwQ158QxU5VM4nK2JrGCM");
 mod garage_tests {
     use super::prelude::*;
     use solana_program::pubkey::Pubkey;
+    use steel::Zeroable;
+
+    /// A fresh Garage holding `total_deposited` at the default max payout, last touched at `now`.
+    fn test_garage(total_deposited: u64, now: i64) -> Garage {
+        Garage {
+            authority: Pubkey::new_unique(),
+            referrer: Pubkey::new_unique(),
+            total_deposited,
+            max_payout: total_deposited * MAX_PAYOUT_MULT / 100,
+            last_action_at: now,
+            created_at: now,
+            ..Garage::zeroed()
+        }
+    }
 
     // ========================================================================
     // Test 1: FuelUp New Account - Fee Splits
@@ -85,14 +99,7 @@ mod garage_tests {
         let garage = Garage {
             authority,
             referrer,
-            total_deposited: gross_amount, // Full deposit for yield calculation
-            total_claimed: 0,
-            max_payout: gross_amount * MAX_PAYOUT_MULT / 100, // 365% of full deposit
-            last_action_at: now,
-            created_at: now,
-            direct_referrals: 0,
-            _padding: 0,
-            lifetime_ref_earnings: 0,
+            ..test_garage(gross_amount, now)
         };
 
         // Verify initialization
@@ -170,24 +177,13 @@ mod garage_tests {
         let initial_deposit = 100 * ONE_FUEL;
         let boost_amount = 50 * ONE_FUEL; // After 5% tax
 
-        let mut garage = Garage {
-            authority: Pubkey::new_unique(),
-            referrer: Pubkey::new_unique(),
-            total_deposited: initial_deposit,
-            total_claimed: 0,
-            max_payout: initial_deposit * MAX_PAYOUT_MULT / 100,
-            last_action_at: 1700000000,
-            created_at: 1700000000,
-            direct_referrals: 0,
-            _padding: 0,
-            lifetime_ref_earnings: 0,
-        };
+        let mut garage = test_garage(initial_deposit, 1700000000);
 
         let old_max_payout = garage.max_payout;
 
         // Simulate boost
         garage.total_deposited += boost_amount;
-        garage.update_max_payout();
+        garage.update_max_payout(MAX_PAYOUT_MULT);
 
         let new_total = initial_deposit + boost_amount; // 150 FUEL
         let expected_max_payout = new_total * MAX_PAYOUT_MULT / 100;
@@ -225,31 +221,109 @@ mod garage_tests {
         let one_day_later = now + ONE_DAY;
         let seven_days_later = now + (7 * ONE_DAY);
 
-        let garage = Garage {
-            authority: Pubkey::new_unique(),
-            referrer: Pubkey::new_unique(),
-            total_deposited,
-            total_claimed: 0,
-            max_payout: total_deposited * MAX_PAYOUT_MULT / 100,
-            last_action_at: now,
-            created_at: now,
-            direct_referrals: 0,
-            _padding: 0,
-            lifetime_ref_earnings: 0,
-        };
+        let garage = test_garage(total_deposited, now);
 
         // After 1 day: 1.5% of 1000 = 15 FUEL
-        let available_1_day = garage.calculate_available(one_day_later);
+        let available_1_day = garage.calculate_available(one_day_later, DAILY_RATE_BPS);
         assert_eq!(available_1_day, 15 * ONE_FUEL, "1 day should accrue 1.5%");
 
         // After 7 days: 10.5% of 1000 = 105 FUEL
-        let available_7_days = garage.calculate_available(seven_days_later);
+        let available_7_days = garage.calculate_available(seven_days_later, DAILY_RATE_BPS);
         assert_eq!(available_7_days, 105 * ONE_FUEL, "7 days should accrue 10.5%");
 
         // Verify daily rate constant
         assert_eq!(DAILY_RATE_BPS, 150, "Daily rate should be 150 BPS (1.5%)");
     }
 
+    /// Test that accrual is continuous across partial days.
+    #[test]
+    fn test_partial_day_accrual() {
+        let total_deposited = 1000 * ONE_FUEL;
+        let now = 1700000000i64;
+
+        let garage = test_garage(total_deposited, now);
+
+        // No time elapsed: nothing accrued
+        assert_eq!(garage.calculate_available(now, DAILY_RATE_BPS), 0);
+
+        // Clock behind last action: nothing accrued
+        assert_eq!(garage.calculate_available(now - ONE_DAY, DAILY_RATE_BPS), 0);
+
+        // Half a day: 0.75% of 1000 = 7.5 FUEL
+        assert_eq!(
+            garage.calculate_available(now + ONE_DAY / 2, DAILY_RATE_BPS),
+            15 * ONE_FUEL / 2,
+            "Half a day should accrue 0.75%"
+        );
+
+        // 1 second short of a day still accrues almost a full day
+        let almost_one_day = garage.calculate_available(now + ONE_DAY - 1, DAILY_RATE_BPS);
+        assert!(almost_one_day > 0 && almost_one_day < 15 * ONE_FUEL);
+
+        // 1.9 days: 2.85% of 1000 = 28.5 FUEL (no partial-day loss)
+        assert_eq!(
+            garage.calculate_available(now + ONE_DAY * 19 / 10, DAILY_RATE_BPS),
+            285 * ONE_FUEL / 10,
+            "1.9 days should accrue 2.85%"
+        );
+
+        // Accrual is monotonic in time
+        let mut prev = 0;
+        for t in (0..=2 * ONE_DAY).step_by(ONE_HOUR as usize) {
+            let available = garage.calculate_available(now + t, DAILY_RATE_BPS);
+            assert!(available >= prev);
+            prev = available;
+        }
+    }
+
+    /// Test that accrual is capped at the remaining payout.
+    #[test]
+    fn test_accrual_capped_at_max_payout() {
+        let total_deposited = 1000 * ONE_FUEL;
+        let now = 1700000000i64;
+        let max_payout = total_deposited * MAX_PAYOUT_MULT / 100;
+
+        let mut garage = Garage {
+            total_claimed: max_payout - 10 * ONE_FUEL,
+            ..test_garage(total_deposited, now)
+        };
+
+        // 1 day would accrue 15 FUEL, but only 10 FUEL remain
+        assert_eq!(garage.calculate_available(now + ONE_DAY, DAILY_RATE_BPS), 10 * ONE_FUEL);
+
+        // Very long periods do not overflow and stay capped
+        assert_eq!(garage.calculate_available(i64::MAX, DAILY_RATE_BPS), 10 * ONE_FUEL);
+
+        // Exhausted garages accrue nothing
+        garage.total_claimed = max_payout;
+        assert_eq!(garage.calculate_available(now + ONE_DAY, DAILY_RATE_BPS), 0);
+    }
+
+    /// Test that a partial claim carries the unclaimed remainder forward.
+    #[test]
+    fn test_partial_claim_carries_remainder() {
+        let total_deposited = 1000 * ONE_FUEL;
+        let now = 1700000000i64;
+
+        let mut garage = test_garage(total_deposited, now);
+
+        // 2 days accrue 30 FUEL; claim 10 FUEL and leave 20 FUEL unclaimed
+        let later = now + 2 * ONE_DAY;
+        let available = garage.calculate_available(later, DAILY_RATE_BPS);
+        assert_eq!(available, 30 * ONE_FUEL);
+        garage.total_claimed += 10 * ONE_FUEL;
+        garage.settle(later, available - 10 * ONE_FUEL, DAILY_RATE_BPS);
+        assert_eq!(garage.calculate_available(later, DAILY_RATE_BPS), 20 * ONE_FUEL);
+
+        // Accrual continues on top of the carried remainder
+        assert_eq!(garage.calculate_available(later + ONE_DAY, DAILY_RATE_BPS), 35 * ONE_FUEL);
+
+        // Settling with nothing unclaimed resets the clock to now
+        garage.settle(later, 0, DAILY_RATE_BPS);
+        assert_eq!(garage.last_action_at, later);
+        assert_eq!(garage.calculate_available(later, DAILY_RATE_BPS), 0);
+    }
+
     // ========================================================================
     // Test 5: Collect Whale Tax
     // ========================================================================
@@ -293,6 +367,216 @@ mod garage_tests {
         assert_eq!(pool_portion, 70 * ONE_FUEL, "Pool should get 70% of whale tax");
     }
 
+    /// Test that the outstanding total tracks obligation and releases exhausted garages.
+    #[test]
+    fn test_garage_tvl_tracks_outstanding() {
+        use bytemuck::Zeroable;
+
+        let total_deposited = 1000 * ONE_FUEL;
+        let now = 1700000000i64;
+        let mut treasury = Treasury::zeroed();
+        let mut garage = test_garage(0, now);
+
+        // Deposit: outstanding grows by the new max payout
+        treasury.track_garage(&mut garage).unwrap();
+        assert_eq!(garage.outstanding_tracked, 1);
+        let before = garage.outstanding();
+        garage.total_deposited = total_deposited;
+        garage.update_max_payout(MAX_PAYOUT_MULT);
+        treasury.update_garage_outstanding(before, garage.outstanding()).unwrap();
+        assert_eq!(treasury.total_garage_outstanding, 3650 * ONE_FUEL);
+
+        // Collect: outstanding shrinks by the claimed amount
+        treasury.track_garage(&mut garage).unwrap();
+        assert_eq!(treasury.total_garage_outstanding, 3650 * ONE_FUEL, "Tracked only once");
+        let before = garage.outstanding();
+        garage.total_claimed += 650 * ONE_FUEL;
+        treasury.update_garage_outstanding(before, garage.outstanding()).unwrap();
+        assert_eq!(treasury.total_garage_outstanding, 3000 * ONE_FUEL);
+
+        // A garage that predates the counter is added at its current obligation
+        let mut legacy = test_garage(100 * ONE_FUEL, now);
+        assert!(
+            treasury.update_garage_outstanding(4000 * ONE_FUEL, 0).is_err(),
+            "Releasing more than is tracked should fail"
+        );
+        treasury.track_garage(&mut legacy).unwrap();
+        assert_eq!(treasury.total_garage_outstanding, 3365 * ONE_FUEL);
+
+        // Exhaustion: garage drops out entirely
+        let before = garage.outstanding();
+        garage.total_claimed = garage.max_payout;
+        treasury.update_garage_outstanding(before, garage.outstanding()).unwrap();
+        assert!(garage.is_exhausted());
+        assert_eq!(treasury.total_garage_outstanding, 365 * ONE_FUEL);
+    }
+
+    /// Test GarageAutomation policy gating for keepers.
+    #[test]
+    fn test_garage_automation_policy() {
+        let now = 1700000000i64;
+        let mut automation = GarageAutomation {
+            authority: Pubkey::new_unique(),
+            executor: EXECUTOR_ADDRESS,
+            balance: 1_000_000,
+            fee: 5_000,
+            strategy: GarageAutomationStrategy::BoostThenCollect as u64,
+            interval: ONE_DAY as u64,
+            boost_count: 2,
+            boosts_since_collect: 0,
+            last_executed_at: now,
+        };
+
+        // Not due until the interval elapses
+        assert!(!automation.can_boost(now + ONE_DAY - 1));
+        assert!(automation.can_boost(now + ONE_DAY));
+
+        // Boost N times, then collect
+        automation.record_boost(now + ONE_DAY);
+        assert!(!automation.can_collect(now + 2 * ONE_DAY));
+        automation.record_boost(now + 2 * ONE_DAY);
+        assert!(!automation.can_boost(now + 3 * ONE_DAY));
+        assert!(automation.can_collect(now + 3 * ONE_DAY));
+        automation.record_collect(now + 3 * ONE_DAY);
+        assert_eq!(automation.boosts_since_collect, 0);
+        assert!(automation.can_boost(now + 4 * ONE_DAY));
+
+        // AutoBoost never collects
+        automation.strategy = GarageAutomationStrategy::AutoBoost as u64;
+        automation.boosts_since_collect = 10;
+        assert!(automation.can_boost(now + 4 * ONE_DAY));
+        assert!(!automation.can_collect(now + 4 * ONE_DAY));
+    }
+
+    /// Test Contrarian mask selection: the N least-deployed squares, ties to the lower index.
+    #[test]
+    fn test_automation_contrarian_squares() {
+        let mut deployed = [100u64; 25];
+        deployed[7] = 0;
+        deployed[3] = 10;
+        deployed[20] = 10;
+        deployed[11] = 50;
+
+        let squares = Automation::contrarian_squares(3, &deployed);
+        let selected: Vec<usize> = (0..25).filter(|&i| squares[i]).collect();
+        assert_eq!(selected, vec![3, 7, 20]);
+
+        let squares = Automation::contrarian_squares(4, &deployed);
+        assert!(squares[11], "Next least-deployed square should be picked");
+        assert_eq!(squares.iter().filter(|&&s| s).count(), 4);
+
+        // Empty round: ties go to the lowest squares.
+        let squares = Automation::contrarian_squares(2, &[0; 25]);
+        assert!(squares[0] && squares[1]);
+        assert_eq!(squares.iter().filter(|&&s| s).count(), 2);
+
+        // Requests are capped at the board size.
+        let squares = Automation::contrarian_squares(40, &deployed);
+        assert!(squares.iter().all(|&s| s));
+        assert!(Automation::contrarian_squares(0, &deployed).iter().all(|&s| !s));
+    }
+
+    /// Test Budget mask selection: the preferred mask trimmed to the per-round and per-day caps.
+    #[test]
+    fn test_automation_budget_squares() {
+        let mut automation = Automation::zeroed();
+        automation.strategy = AutomationStrategy::Budget as u64;
+        automation.amount = 100;
+        automation.mask = 0b1_0110_1001; // Squares 0, 3, 5, 6, 8
+        let (round_id, day) = (10, 19_000);
+
+        // No caps: the full preferred mask.
+        assert_eq!(automation.budget_squares(round_id, day), automation.preferred_squares());
+        assert_eq!(automation.budget_remaining(round_id, day), u64::MAX);
+
+        // Round cap of 250 affords 2 squares, taken lowest first.
+        automation.round_budget = 250;
+        let squares = automation.budget_squares(round_id, day);
+        let selected: Vec<usize> = (0..25).filter(|&i| squares[i]).collect();
+        assert_eq!(selected, vec![0, 3]);
+
+        // Spending counts against the round and the day.
+        automation.daily_budget = 400;
+        automation.record_spend(round_id, day, 200);
+        assert_eq!(automation.budget_remaining(round_id, day), 50);
+        assert!(automation.budget_squares(round_id, day).iter().all(|&s| !s));
+
+        // A new round restores the round cap, limited by what is left of the day.
+        assert_eq!(automation.budget_remaining(round_id + 1, day), 200);
+        automation.record_spend(round_id + 1, day, 200);
+        assert_eq!(automation.budget_remaining(round_id + 2, day), 0);
+
+        // A new day restores both caps.
+        assert_eq!(automation.budget_remaining(round_id + 2, day + 1), 250);
+        assert_eq!(automation.total_spent, 400);
+    }
+
+    /// Test StopLoss tracking: losses are spend minus winnings, and the stop triggers above the threshold.
+    #[test]
+    fn test_automation_stop_loss() {
+        let mut automation = Automation::zeroed();
+        automation.strategy = AutomationStrategy::StopLoss as u64;
+        automation.amount = 100;
+        automation.mask = 0b111;
+        automation.stop_loss = 500;
+        automation.rewards_seen = 1_000;
+
+        // Spend 600, win 150 of it back: 450 lost.
+        automation.record_spend(1, 0, 300);
+        automation.record_spend(2, 0, 300);
+        automation.record_rewards(0, 1_150);
+        assert_eq!(automation.total_won, 150);
+        assert_eq!(automation.losses(), 450);
+        assert!(!automation.is_stop_loss_hit());
+
+        // Rewards already seen are not credited twice.
+        automation.record_rewards(0, 1_150);
+        assert_eq!(automation.total_won, 150);
+
+        // Losses at the threshold do not stop; above it they do.
+        automation.record_spend(3, 0, 50);
+        assert!(!automation.is_stop_loss_hit());
+        automation.record_spend(4, 0, 1);
+        assert!(automation.is_stop_loss_hit());
+
+        // Net winnings never count as losses, and a zero threshold disables the stop.
+        // A reading from another table's miner only moves the baseline.
+        automation.record_rewards(1, 5_000);
+        assert_eq!(automation.total_won, 150);
+        automation.record_rewards(1, 5_050);
+        assert_eq!(automation.total_won, 200);
+        automation.record_rewards(0, 1_150);
+        assert_eq!(automation.total_won, 200);
+
+        automation.record_rewards(0, 10_000);
+        assert_eq!(automation.losses(), 0);
+        automation.stop_loss = 0;
+        automation.total_won = 0;
+        assert!(!automation.is_stop_loss_hit());
+    }
+
+    /// Test Automation lifetime limits: expiry takes precedence, and zero disables each limit.
+    #[test]
+    fn test_automation_lifetime_limits() {
+        let mut automation = Automation::zeroed();
+        assert_eq!(automation.limit_hit(u64::MAX), None, "Zero limits never stop");
+
+        // Max rounds.
+        automation.max_rounds = 50;
+        automation.rounds_played = 49;
+        assert_eq!(automation.limit_hit(1_000), None);
+        automation.rounds_played = 50;
+        assert_eq!(automation.limit_hit(1_000), Some(AutomationStopReason::MaxRounds));
+
+        // Expiry applies from the expiry slot onward and takes precedence.
+        automation.expires_at_slot = 2_000;
+        assert_eq!(automation.limit_hit(1_999), Some(AutomationStopReason::MaxRounds));
+        assert_eq!(automation.limit_hit(2_000), Some(AutomationStopReason::Expired));
+        automation.max_rounds = 0;
+        assert_eq!(automation.limit_hit(1_999), None);
+        assert_eq!(automation.limit_hit(2_001), Some(AutomationStopReason::Expired));
+    }
+
     // ========================================================================
     // Test 6: Stash - Sprint Rewards to Garage
     // ========================================================================
@@ -316,22 +600,11 @@ mod garage_tests {
         let initial_deposit = 100 * ONE_FUEL;
         let stash_amount = 50 * ONE_FUEL;
 
-        let mut garage = Garage {
-            authority: Pubkey::new_unique(),
-            referrer: Pubkey::new_unique(),
-            total_deposited: initial_deposit,
-            total_claimed: 0,
-            max_payout: initial_deposit * MAX_PAYOUT_MULT / 100,
-            last_action_at: 1700000000,
-            created_at: 1700000000,
-            direct_referrals: 0,
-            _padding: 0,
-            lifetime_ref_earnings: 0,
-        };
+        let mut garage = test_garage(initial_deposit, 1700000000);
 
         // Simulate stash (0% fee, full amount added)
         garage.total_deposited += stash_amount;
-        garage.update_max_payout();
+        garage.update_max_payout(MAX_PAYOUT_MULT);
 
         assert_eq!(garage.total_deposited, 150 * ONE_FUEL);
         assert_eq!(garage.max_payout, 150 * ONE_FUEL * MAX_PAYOUT_MULT / 100);
@@ -381,18 +654,7 @@ mod garage_tests {
         let deposit = 100 * ONE_FUEL;
         let max_payout = deposit * MAX_PAYOUT_MULT / 100; // 365 FUEL
 
-        let mut garage = Garage {
-            authority: Pubkey::new_unique(),
-            referrer: Pubkey::new_unique(),
-            total_deposited: deposit,
-            total_claimed: 0,
-            max_payout,
-            last_action_at: 1700000000,
-            created_at: 1700000000,
-            direct_referrals: 0,
-            _padding: 0,
-            lifetime_ref_earnings: 0,
-        };
+        let mut garage = test_garage(deposit, 1700000000);
 
         // Initially not exhausted
         assert!(!garage.is_exhausted());
@@ -406,10 +668,401 @@ mod garage_tests {
         assert!(garage.is_exhausted());
 
         // No more rewards available
-        let available = garage.calculate_available(garage.last_action_at + ONE_DAY * 30);
+        let available =
+            garage.calculate_available(garage.last_action_at + ONE_DAY * 30, DAILY_RATE_BPS);
         assert_eq!(available, 0, "Exhausted garage should have 0 available");
     }
 
+    /// Test that an exhausted Garage restarts with lifetime totals archived.
+    #[test]
+    fn test_new_cycle_after_exhaustion() {
+        let deposit = 100 * ONE_FUEL;
+        let referrer = Pubkey::new_unique();
+        let now = 1700000000i64;
+
+        let mut garage = Garage {
+            referrer,
+            direct_referrals: 3,
+            lifetime_ref_earnings: 7 * ONE_FUEL,
+            ..test_garage(deposit, now)
+        };
+
+        // Empty garage is not exhausted
+        let mut empty = garage;
+        empty.total_deposited = 0;
+        empty.max_payout = 0;
+        assert!(!empty.is_exhausted());
+
+        // Exhaust and restart
+        garage.total_claimed = garage.max_payout;
+        assert!(garage.is_exhausted());
+        let event = garage.start_new_cycle(now + ONE_DAY);
+        assert_eq!(event.authority, garage.authority);
+        assert_eq!(event.cycle, 1);
+        assert_eq!(event.cycle_deposited, deposit);
+        assert_eq!(event.cycle_claimed, 365 * ONE_FUEL);
+        assert_eq!(event.lifetime_claimed, 365 * ONE_FUEL);
+        assert_eq!(event.ts, now + ONE_DAY);
+
+        assert!(!garage.is_exhausted());
+        assert_eq!(garage.cycle, 1);
+        assert_eq!(garage.lifetime_deposited, deposit);
+        assert_eq!(garage.lifetime_claimed, 365 * ONE_FUEL);
+        assert_eq!(garage.total_deposited, 0);
+        assert_eq!(garage.total_claimed, 0);
+        assert_eq!(garage.max_payout, 0);
+        assert_eq!(garage.last_action_at, now + ONE_DAY);
+
+        // Referral data carries over
+        assert_eq!(garage.referrer, referrer);
+        assert_eq!(garage.direct_referrals, 3);
+        assert_eq!(garage.lifetime_ref_earnings, 7 * ONE_FUEL);
+    }
+
+    /// Test referral code normalization.
+    #[test]
+    fn test_referral_code_normalization() {
+        let code = ReferralCode::normalize(" PitCrew7 ").unwrap();
+        assert_eq!(&code[..8], b"PITCREW7");
+        assert!(code[8..].iter().all(|c| *c == 0));
+        assert!(ReferralCode::is_normalized(&code));
+
+        // Same code in any case maps to the same PDA
+        assert_eq!(
+            referral_code_pda(ReferralCode::normalize("pitcrew").unwrap()),
+            referral_code_pda(ReferralCode::normalize("PITCREW").unwrap()),
+        );
+
+        // Invalid codes
+        assert!(ReferralCode::normalize("AB").is_none(), "Too short");
+        assert!(ReferralCode::normalize("ABCDEFGHIJKLMNOPQ").is_none(), "Too long");
+        assert!(ReferralCode::normalize("PIT-CREW").is_none(), "Non-alphanumeric");
+        assert!(ReferralCode::normalize("PIT CREW").is_none(), "Inner whitespace");
+
+        // Raw bytes must already be normalized
+        let mut lower = code;
+        lower[0] = b'p';
+        assert!(!ReferralCode::is_normalized(&lower));
+        let mut gap = code;
+        gap[2] = 0;
+        assert!(!ReferralCode::is_normalized(&gap));
+
+        let account = ReferralCode {
+            code,
+            authority: Pubkey::new_unique(),
+            created_at: 0,
+        };
+        assert_eq!(account.code_str(), "PITCREW7");
+    }
+
+    /// Test that multi-level referral shares split the 10% referral fee 6% / 3% / 1%.
+    #[test]
+    fn test_multi_level_referral_shares() {
+        let gross_amount = 1000 * ONE_FUEL;
+        let params = ProtocolParams::default();
+        let shares: Vec<u64> = params
+            .ref_level_bps
+            .iter()
+            .map(|bps| gross_amount * bps / DENOMINATOR_BPS)
+            .collect();
+        assert_eq!(shares, vec![60 * ONE_FUEL, 30 * ONE_FUEL, 10 * ONE_FUEL]);
+
+        let ref_fee = gross_amount * FUEL_UP_REF_BPS / DENOMINATOR_BPS;
+        assert_eq!(shares.iter().sum::<u64>(), ref_fee);
+        assert_eq!(params.ref_unallocated_bps(), 0);
+    }
+
+    /// Test that the referral ledger accumulates per-referrer totals.
+    #[test]
+    fn test_referral_ledger_record() {
+        let referee = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+        let mut ledger = ReferralLedger {
+            referee,
+            referrer,
+            total_volume: 0,
+            total_earnings: 0,
+            total_redirected: 0,
+            deposit_count: 0,
+            created_at: 0,
+            last_deposit_at: 0,
+        };
+
+        ledger.record(referrer, 100 * ONE_FUEL, 10 * ONE_FUEL, 0, 10);
+        ledger.record(referrer, 50 * ONE_FUEL, 0, 5 * ONE_FUEL, 20);
+        assert_eq!(ledger.total_volume, 150 * ONE_FUEL);
+        assert_eq!(ledger.total_earnings, 10 * ONE_FUEL);
+        assert_eq!(ledger.total_redirected, 5 * ONE_FUEL);
+        assert_eq!(ledger.deposit_count, 2);
+        assert_eq!(ledger.last_deposit_at, 20);
+
+        // A different referrer restarts the totals
+        let new_referrer = Pubkey::new_unique();
+        ledger.record(new_referrer, 20 * ONE_FUEL, 2 * ONE_FUEL, 0, 30);
+        assert_eq!(ledger.referrer, new_referrer);
+        assert_eq!(ledger.total_volume, 20 * ONE_FUEL);
+        assert_eq!(ledger.total_earnings, 2 * ONE_FUEL);
+        assert_eq!(ledger.total_redirected, 0);
+        assert_eq!(ledger.deposit_count, 1);
+    }
+
+    /// Test that each role maps to its own Config field and transfers respect the timelock.
+    #[test]
+    fn test_config_roles() {
+        let mut config = Config::zeroed();
+        let roles = [
+            Role::Admin,
+            Role::TreasuryOperator,
+            Role::Pauser,
+            Role::FeeCollector,
+        ];
+        let holders: Vec<Pubkey> = roles.iter().map(|_| Pubkey::new_unique()).collect();
+        for (role, holder) in roles.iter().zip(holders.iter()) {
+            config.set_role(*role, *holder);
+        }
+        for (role, holder) in roles.iter().zip(holders.iter()) {
+            assert_eq!(config.role(*role), *holder, "Role {:?} mismatch", role);
+        }
+        assert_eq!(config.admin, holders[0]);
+        assert_eq!(config.treasury_operator, holders[1]);
+        assert_eq!(config.pauser, holders[2]);
+        assert_eq!(config.fee_collector, holders[3]);
+        assert!(config.has_role(Role::Pauser, &holders[2]));
+        assert!(!config.has_role(Role::Pauser, &holders[1]));
+
+        // Roles set before the backfill are kept; unset roles get their defaults.
+        let mut config = Config::zeroed();
+        config.admin = holders[0];
+        config.pauser = holders[2];
+        assert!(!config.has_role(Role::FeeCollector, &Pubkey::default()));
+        config.backfill_roles();
+        assert_eq!(config.admin, holders[0]);
+        assert_eq!(config.pauser, holders[2]);
+        assert_eq!(config.fee_collector, ADMIN_FEE_COLLECTOR);
+        assert_eq!(config.treasury_operator, BURY_AUTHORITY);
+
+        let transfer = RoleTransfer {
+            role: Role::Admin as u64,
+            authority: holders[0],
+            recipient: Pubkey::new_unique(),
+            created_at: 1_000,
+        };
+        assert!(transfer.is_ready(1_000, 0));
+        assert!(!transfer.is_ready(1_000 + ONE_DAY - 1, ONE_DAY as u64));
+        assert!(transfer.is_ready(1_000 + ONE_DAY, ONE_DAY as u64));
+    }
+
+    #[test]
+    fn test_board_table_params() {
+        let params = ProtocolParams::default();
+        let mut board = Board::zeroed();
+        assert!(board.has_valid_params());
+        assert_eq!(board.round_slots(&params), params.round_slots);
+        assert_eq!(board.intermission_slots(&params), params.intermission_slots);
+
+        // Overrides replace the Config defaults.
+        board.round_slots = MIN_ROUND_SLOTS;
+        board.intermission_slots = 10;
+        assert!(board.has_valid_params());
+        assert_eq!(board.round_slots(&params), MIN_ROUND_SLOTS);
+        assert_eq!(board.intermission_slots(&params), 10);
+
+        // Overrides are bounds-checked like the protocol params.
+        board.round_slots = MIN_ROUND_SLOTS - 1;
+        assert!(!board.has_valid_params(), "Too-short round should be rejected");
+        board.round_slots = MAX_ROUND_SLOTS + 1;
+        assert!(!board.has_valid_params(), "Too-long round should be rejected");
+        board.round_slots = 0;
+        board.intermission_slots = MAX_INTERMISSION_SLOTS + 1;
+        assert!(!board.has_valid_params(), "Too-long intermission should be rejected");
+    }
+
+    #[test]
+    fn test_config_pause_flags() {
+        let mut config = Config::zeroed();
+        assert!(!config.is_paused(PAUSE_ALL));
+
+        config.paused = PAUSE_DEPLOY | PAUSE_GARAGE_WITHDRAW;
+        assert!(config.is_paused(PAUSE_DEPLOY));
+        assert!(config.is_paused(PAUSE_GARAGE_WITHDRAW));
+        assert!(!config.is_paused(PAUSE_RESET));
+        assert!(!config.is_paused(PAUSE_GARAGE_DEPOSIT));
+        assert!(config.is_paused(PAUSE_CLAIM | PAUSE_DEPLOY));
+        assert!(!config.is_paused(PAUSE_CLAIM | PAUSE_GARAGE_DEPOSIT));
+
+        let flags = [
+            PAUSE_DEPLOY,
+            PAUSE_RESET,
+            PAUSE_GARAGE_DEPOSIT,
+            PAUSE_GARAGE_WITHDRAW,
+            PAUSE_CLAIM,
+        ];
+        assert_eq!(flags.iter().fold(0, |acc, f| acc | f), PAUSE_ALL);
+        assert_eq!(PAUSE_ALL.count_ones() as usize, flags.len());
+    }
+
+    /// Test that pending changes validate their payload and apply to the right Config field.
+    #[test]
+    fn test_pending_change_apply() {
+        let mut config = Config::zeroed();
+        let mut change = PendingChange::zeroed();
+        change.eta = 1_000;
+        assert!(!change.is_ready(999));
+        assert!(change.is_ready(1_000));
+
+        // Timelock delay is bounded
+        change.kind = ChangeKind::TimelockDelay as u64;
+        change.value = MAX_TIMELOCK_DELAY + 1;
+        assert!(!change.is_valid(), "Excessive delay should be rejected");
+        change.value = MIN_TIMELOCK_DELAY - 1;
+        assert!(!change.is_valid(), "Delays below the floor should be rejected");
+        change.value = ONE_DAY as u64;
+        assert!(change.is_valid());
+        change.apply(&mut config);
+        assert_eq!(config.timelock_delay, ONE_DAY as u64);
+
+        // Var changes name a table and are consumed by NewVar, not executed
+        change.kind = ChangeKind::Var as u64;
+        change.value = 3;
+        assert!(change.is_valid());
+        assert!(!change.is_executable());
+        change.value = u8::MAX as u64 + 1;
+        assert!(!change.is_valid());
+        change.apply(&mut config);
+        assert_eq!(config.timelock_delay, ONE_DAY as u64, "Var changes leave Config untouched");
+
+        // Table changes are bounds-checked like SetTable and applied to the Board
+        let mut board = Board::zeroed();
+        change.kind = ChangeKind::Table as u64;
+        change.value = 2;
+        change.min_deploy = 100_000_000;
+        change.round_slots = MAX_ROUND_SLOTS + 1;
+        assert!(!change.is_valid(), "Out-of-bounds round length should be rejected");
+        change.round_slots = MIN_ROUND_SLOTS;
+        change.intermission_slots = MAX_INTERMISSION_SLOTS;
+        assert!(change.is_valid());
+        assert!(change.is_executable());
+        change.apply(&mut config);
+        assert_eq!(config.timelock_delay, ONE_DAY as u64, "Table changes leave Config untouched");
+        change.apply_table(&mut board);
+        assert_eq!(board.min_deploy, 100_000_000);
+        assert_eq!(board.round_slots, MIN_ROUND_SLOTS);
+        assert_eq!(board.intermission_slots, MAX_INTERMISSION_SLOTS);
+
+        change.kind = ChangeKind::BackstopCap as u64;
+        change.value = 42 * ONE_FUEL;
+        change.apply(&mut config);
+        assert_eq!(config.garage_backstop_cap, 42 * ONE_FUEL);
+
+        // Params changes are bounds-checked and bump the layout version
+        change.kind = ChangeKind::Params as u64;
+        assert!(!change.is_valid(), "Zeroed params should be rejected");
+        change.params = ProtocolParams::default();
+        assert!(change.is_valid());
+        change.apply(&mut config);
+        assert_eq!(config.params, ProtocolParams::default());
+        assert_eq!(config.version, CONFIG_VERSION);
+
+        // Unknown kinds are rejected
+        change.kind = 99;
+        assert!(!change.is_valid());
+    }
+
+    /// Test that a fee split proposed with ProposeChange reaches Config once executed.
+    #[test]
+    fn test_fee_rate_change() {
+        let mut config = Config::zeroed();
+        config.params = ProtocolParams::default();
+
+        // Encode the new split the way ProposeChange carries it.
+        let mut params = config.params;
+        params.sprint_team_fee_bps = 200;
+        params.sprint_buyback_fee_bps = 800;
+        let mut change = PendingChange::zeroed();
+        change.kind = ChangeKind::Params as u64;
+        change.params = SetParams::from(params).params();
+        assert!(change.is_valid());
+        assert!(change.is_executable());
+        change.apply(&mut config);
+        assert_eq!(config.params.sprint_team_fee_bps, 200);
+        assert_eq!(config.params.sprint_buyback_fee_bps, 800);
+        assert_eq!(config.params.daily_rate_bps, DAILY_RATE_BPS, "Other params are kept");
+
+        // Splits above the protocol fee cap are rejected
+        change.params.sprint_team_fee_bps = MAX_SPRINT_TEAM_FEE_BPS;
+        change.params.sprint_buyback_fee_bps = MAX_SPRINT_PROTOCOL_FEE_BPS;
+        assert!(!change.is_valid());
+    }
+
+    /// Test that default protocol params match the compiled constants and pass validation.
+    #[test]
+    fn test_protocol_params_bounds() {
+        let params = ProtocolParams::default();
+        assert!(params.is_valid(), "Default params should be valid");
+        assert_eq!(params.daily_rate_bps, DAILY_RATE_BPS);
+        assert_eq!(params.round_slots, ROUND_SLOTS);
+        assert_eq!(
+            params.sprint_team_fee_bps + params.sprint_buyback_fee_bps,
+            SPRINT_PROTOCOL_FEE_BPS
+        );
+        for (balance, tvl) in [(0, 0), (50, 10_000), (150, 10_000), (10_000, 10_000)] {
+            assert_eq!(
+                params.whale_tax_bps(balance, tvl),
+                calculate_whale_tax_bps(balance, tvl)
+            );
+        }
+
+        // Out of bounds scalars
+        let mut p = params;
+        p.daily_rate_bps = 0;
+        assert!(!p.is_valid(), "Zero daily rate should be rejected");
+        let mut p = params;
+        p.daily_rate_bps = MAX_DAILY_RATE_BPS + 1;
+        assert!(!p.is_valid(), "Excessive daily rate should be rejected");
+        let mut p = params;
+        p.max_payout_mult = MIN_PAYOUT_MULT - 1;
+        assert!(!p.is_valid(), "Payout below principal should be rejected");
+        let mut p = params;
+        p.collect_tax_bps = COLLECT_TEAM_BPS - 1;
+        assert!(!p.is_valid(), "Collect tax below team share should be rejected");
+        let mut p = params;
+        p.sprint_buyback_fee_bps = MAX_SPRINT_PROTOCOL_FEE_BPS - p.sprint_team_fee_bps + 1;
+        assert!(!p.is_valid(), "Excessive Sprint protocol fee should be rejected");
+        let mut p = params;
+        p.round_slots = MIN_ROUND_SLOTS - 1;
+        assert!(!p.is_valid(), "Too-short round should be rejected");
+        let mut p = params;
+        p.claim_expiry_slots = MIN_CLAIM_EXPIRY_SLOTS - 1;
+        assert!(!p.is_valid(), "Too-short claim window should be rejected");
+        let mut p = params;
+        p.claim_expiry_slots = MAX_CLAIM_EXPIRY_SLOTS + 1;
+        assert!(!p.is_valid(), "Too-long claim window should be rejected");
+        assert_eq!(params.checkpoint_window_slots(), TWELVE_HOURS_SLOTS);
+
+        // Referral levels may not exceed the referral fee; any remainder goes to the pool
+        let mut p = params;
+        p.ref_level_bps[0] += 1;
+        assert!(!p.is_valid(), "Referral levels above the referral fee should be rejected");
+        let mut p = params;
+        p.ref_level_bps = [500, 250, 0];
+        assert!(p.is_valid());
+        assert_eq!(p.ref_unallocated_bps(), FUEL_UP_REF_BPS - 750);
+
+        // Whale tables must be ordered
+        let mut p = params;
+        p.whale_tax_threshold_bps.swap(0, 1);
+        assert!(!p.is_valid(), "Unordered thresholds should be rejected");
+        let mut p = params;
+        p.whale_tax_rate_bps[0] = p.whale_tax_rate_bps[1] + 1;
+        assert!(!p.is_valid(), "Decreasing rates should be rejected");
+
+        // Custom schedule is applied
+        let mut p = params;
+        p.whale_tax_rate_bps = [1000; WHALE_TAX_TIERS];
+        assert!(p.is_valid());
+        assert_eq!(p.whale_tax_bps(100, 10_000), 1000);
+    }
+
     /// Test max payout multiplier constant.
     #[test]
     fn test_max_payout_multiplier() {
@@ -419,18 +1072,10 @@ mod garage_tests {
         let expected_max = deposit * 365 / 100;
 
         let mut garage = Garage {
-            authority: Pubkey::new_unique(),
-            referrer: Pubkey::new_unique(),
-            total_deposited: deposit,
-            total_claimed: 0,
             max_payout: 0,
-            last_action_at: 0,
-            created_at: 0,
-            direct_referrals: 0,
-            _padding: 0,
-            lifetime_ref_earnings: 0,
+            ..test_garage(deposit, 0)
         };
-        garage.update_max_payout();
+        garage.update_max_payout(MAX_PAYOUT_MULT);
 
         assert_eq!(garage.max_payout, expected_max, "Max payout should be 365% of deposit");
     }
@@ -452,21 +1097,13 @@ mod garage_tests {
         let max_payout = deposit * MAX_PAYOUT_MULT / 100; // 365 FUEL
 
         let garage = Garage {
-            authority: Pubkey::new_unique(),
-            referrer: Pubkey::new_unique(),
-            total_deposited: deposit,
             total_claimed: max_payout - (10 * ONE_FUEL), // Only 10 FUEL remaining
-            max_payout,
-            last_action_at: 1700000000,
-            created_at: 1700000000,
-            direct_referrals: 0,
-            _padding: 0,
-            lifetime_ref_earnings: 0,
+            ..test_garage(deposit, 1700000000)
         };
 
         // Even after 365 days, should only get remaining 10 FUEL
         let far_future = garage.last_action_at + ONE_DAY * 365;
-        let available = garage.calculate_available(far_future);
+        let available = garage.calculate_available(far_future, DAILY_RATE_BPS);
 
         assert_eq!(available, 10 * ONE_FUEL, "Available should be capped at remaining payout");
     }
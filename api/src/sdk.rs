@@ -3,7 +3,10 @@ use spl_associated_token_account::get_associated_token_address;
 use steel::*;
 
 use crate::{
-    consts::{BOARD, CONFIG, MINT_ADDRESS, ROUND, SOL_MINT, TREASURY},
+    consts::{
+        BOARD, CONFIG, DEFAULT_TABLE_ID, HOUSE_ADDRESS, MINT_ADDRESS, REFERRAL_CODE_MAX_LEN, ROUND,
+        SOL_MINT, TREASURY,
+    },
     instruction::*,
     state::*,
 };
@@ -22,6 +25,16 @@ pub fn program_log(accounts: &[AccountInfo], msg: &[u8]) -> Result<(), ProgramEr
     invoke_signed(&log(*accounts[0].key, msg), accounts, &crate::ID, &[BOARD])
 }
 
+/// Logs a message signed by the board of the given table.
+pub fn table_log(table_id: u8, accounts: &[AccountInfo], msg: &[u8]) -> Result<(), ProgramError> {
+    invoke_signed(
+        &log(*accounts[0].key, msg),
+        accounts,
+        &crate::ID,
+        &[BOARD, table_seed(&table_id)],
+    )
+}
+
 // let [signer_info, automation_info, executor_info, miner_info, system_program] = accounts else {
 
 pub fn automate(
@@ -33,9 +46,15 @@ pub fn automate(
     mask: u64,
     strategy: u8,
     reload: bool,
+    round_budget: u64,
+    daily_budget: u64,
+    stop_loss: u64,
+    max_rounds: u64,
+    expires_at_slot: u64,
 ) -> Instruction {
     let automation_address = automation_pda(signer).0;
-    let miner_address = miner_pda(signer).0;
+    let miner_address = miner_pda(signer, DEFAULT_TABLE_ID).0;
+    let config_address = config_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -44,6 +63,7 @@ pub fn automate(
             AccountMeta::new(executor, false),
             AccountMeta::new(miner_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(config_address, false),
         ],
         data: Automate {
             amount: amount.to_le_bytes(),
@@ -52,28 +72,34 @@ pub fn automate(
             mask: mask.to_le_bytes(),
             strategy: strategy as u8,
             reload: (reload as u64).to_le_bytes(),
+            round_budget: round_budget.to_le_bytes(),
+            daily_budget: daily_budget.to_le_bytes(),
+            stop_loss: stop_loss.to_le_bytes(),
+            max_rounds: max_rounds.to_le_bytes(),
+            expires_at_slot: expires_at_slot.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
-pub fn claim_sol(signer: Pubkey) -> Instruction {
-    let miner_address = miner_pda(signer).0;
+pub fn claim_sol(signer: Pubkey, table_id: u8) -> Instruction {
+    let miner_address = miner_pda(signer, table_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(miner_address, false),
+            AccountMeta::new_readonly(config_pda().0, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: ClaimSOL {}.to_bytes(),
     }
 }
 
-// let [signer_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+// let [signer_info, miner_info, config_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
 
-pub fn claim_ore(signer: Pubkey) -> Instruction {
-    let miner_address = miner_pda(signer).0;
+pub fn claim_ore(signer: Pubkey, table_id: u8) -> Instruction {
+    let miner_address = miner_pda(signer, table_id).0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
     let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
@@ -82,6 +108,7 @@ pub fn claim_ore(signer: Pubkey) -> Instruction {
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(miner_address, false),
+            AccountMeta::new_readonly(config_pda().0, false),
             AccountMeta::new(MINT_ADDRESS, false),
             AccountMeta::new(recipient_address, false),
             AccountMeta::new(treasury_address, false),
@@ -94,21 +121,23 @@ pub fn claim_ore(signer: Pubkey) -> Instruction {
     }
 }
 
-// let [signer_info, authority_info, automation_info, board_info, miner_info, round_info, system_program] =
+// let [signer_info, authority_info, automation_info, board_info, config_info, miner_info, round_info, system_program, ore_program] =
 
+/// Deploys to a table. `var` is the entropy var pinned to the table's board (`Board.var`).
 pub fn deploy(
     signer: Pubkey,
     authority: Pubkey,
+    table_id: u8,
+    var: Pubkey,
     amount: u64,
     round_id: u64,
     squares: [bool; 25],
 ) -> Instruction {
     let automation_address = automation_pda(authority).0;
-    let board_address = board_pda().0;
+    let board_address = board_pda(table_id).0;
     let config_address = config_pda().0;
-    let miner_address = miner_pda(authority).0;
-    let round_address = round_pda(round_id).0;
-    let entropy_var_address = entropy_api::state::var_pda(board_address, 0).0;
+    let miner_address = miner_pda(authority, table_id).0;
+    let round_address = round_pda(table_id, round_id).0;
 
     // Convert array of 25 booleans into a 32-bit mask where each bit represents whether
     // that square index is selected (1) or not (0)
@@ -132,21 +161,65 @@ pub fn deploy(
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(crate::ID, false),
             // Entropy accounts.
-            AccountMeta::new(entropy_var_address, false),
+            AccountMeta::new(var, false),
             AccountMeta::new_readonly(entropy_api::ID, false),
         ],
         data: Deploy {
             amount: amount.to_le_bytes(),
             squares: mask.to_le_bytes(),
+            table_id,
         }
         .to_bytes(),
     }
 }
 
+/// Deploys a different amount to each listed square. `var` is the table's `Board.var`.
+pub fn deploy_multi(
+    signer: Pubkey,
+    table_id: u8,
+    var: Pubkey,
+    round_id: u64,
+    squares: &[(u8, u64)],
+) -> Instruction {
+    let automation_address = automation_pda(signer).0;
+    let board_address = board_pda(table_id).0;
+    let config_address = config_pda().0;
+    let miner_address = miner_pda(signer, table_id).0;
+    let round_address = round_pda(table_id, round_id).0;
+
+    // Append one (square, amount) entry per square after the header.
+    let mut data = DeployMulti { table_id }.to_bytes();
+    for &(square, amount) in squares {
+        data.extend_from_slice(bytemuck::bytes_of(&DeploySquare {
+            square,
+            amount: amount.to_le_bytes(),
+        }));
+    }
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(signer, false),
+            AccountMeta::new(automation_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(round_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+            // Entropy accounts.
+            AccountMeta::new(var, false),
+            AccountMeta::new_readonly(entropy_api::ID, false),
+        ],
+        data,
+    }
+}
+
 // let [pool, user_source_token, user_destination_token, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, protocol_token_fee, user_key, vault_program, token_program] =
 
 pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8]) -> Instruction {
-    let board_address = board_pda().0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
     let config_address = config_pda().0;
     let mint_address = MINT_ADDRESS;
     let treasury_address = treasury_pda().0;
@@ -180,7 +253,7 @@ pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8])
 // let [signer_info, sender_info, board_info, mint_info, treasury_info, treasury_ore_info, token_program, ore_program] =
 
 pub fn bury(signer: Pubkey, amount: u64) -> Instruction {
-    let board_address = board_pda().0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
     let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
     let mint_address = MINT_ADDRESS;
     let treasury_address = treasury_pda().0;
@@ -209,7 +282,7 @@ pub fn bury(signer: Pubkey, amount: u64) -> Instruction {
 // let [signer_info, board_info, config_info, manager_info, manager_sol_info, treasury_info, treasury_sol_info, token_program, ore_program] =
 
 pub fn liq(signer: Pubkey, manager: Pubkey) -> Instruction {
-    let board_address = board_pda().0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
     let config_address = config_pda().0;
     let manager_sol_address = get_associated_token_address(&manager, &SOL_MINT);
     let treasury_address = treasury_pda().0;
@@ -255,21 +328,23 @@ pub fn wrap(signer: Pubkey, amount: u64) -> Instruction {
 
 // let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
 
+/// Resets a table for its next round. `var` is the table's `Board.var`.
 pub fn reset(
     signer: Pubkey,
     fee_collector: Pubkey,
+    table_id: u8,
+    var: Pubkey,
     round_id: u64,
     top_miner: Pubkey,
 ) -> Instruction {
-    let board_address = board_pda().0;
+    let board_address = board_pda(table_id).0;
     let config_address = config_pda().0;
     let mint_address = MINT_ADDRESS;
-    let round_address = round_pda(round_id).0;
-    let round_next_address = round_pda(round_id + 1).0;
-    let top_miner_address = miner_pda(top_miner).0;
+    let round_address = round_pda(table_id, round_id).0;
+    let round_next_address = round_pda(table_id, round_id + 1).0;
+    let top_miner_address = miner_pda(top_miner, table_id).0;
     let treasury_address = treasury_pda().0;
     let treasury_tokens_address = treasury_tokens_address();
-    let entropy_var_address = entropy_api::state::var_pda(board_address, 0).0;
     let mint_authority_address = ore_mint_api::state::authority_pda().0;
     Instruction {
         program_id: crate::ID,
@@ -289,22 +364,22 @@ pub fn reset(
             AccountMeta::new_readonly(crate::ID, false),
             AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
             // Entropy accounts.
-            AccountMeta::new(entropy_var_address, false),
+            AccountMeta::new(var, false),
             AccountMeta::new_readonly(entropy_api::ID, false),
             // Mint accounts.
             AccountMeta::new(mint_authority_address, false),
             AccountMeta::new_readonly(ore_mint_api::ID, false),
         ],
-        data: Reset {}.to_bytes(),
+        data: Reset { table_id }.to_bytes(),
     }
 }
 
 // let [signer_info, board_info, rent_payer_info, round_info, treasury_info, system_program] =
 
-pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
-    let board_address = board_pda().0;
+pub fn close(signer: Pubkey, table_id: u8, round_id: u64, rent_payer: Pubkey) -> Instruction {
+    let board_address = board_pda(table_id).0;
     let treasury_address = treasury_pda().0;
-    let round_address = round_pda(round_id).0;
+    let round_address = round_pda(table_id, round_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -315,32 +390,214 @@ pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
-        data: Close {}.to_bytes(),
+        data: Close { table_id }.to_bytes(),
     }
 }
 
-// let [signer_info, automation_info, board_info, miner_info, round_info, treasury_info, system_program] =
+// let [signer_info, board_info, config_info, miner_info, round_info, treasury_info, system_program] =
 
-pub fn checkpoint(signer: Pubkey, authority: Pubkey, round_id: u64) -> Instruction {
-    let miner_address = miner_pda(authority).0;
-    let board_address = board_pda().0;
-    let round_address = round_pda(round_id).0;
+pub fn checkpoint(signer: Pubkey, authority: Pubkey, table_id: u8, round_id: u64) -> Instruction {
+    let miner_address = miner_pda(authority, table_id).0;
+    let board_address = board_pda(table_id).0;
+    let round_address = round_pda(table_id, round_id).0;
     let treasury_address = treasury_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(config_pda().0, false),
             AccountMeta::new(miner_address, false),
             AccountMeta::new(round_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
-        data: Checkpoint {}.to_bytes(),
+        data: Checkpoint { table_id }.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, pending_change_info, board_info, system_program, ore_program] =
+
+pub fn propose_change(
+    signer: Pubkey,
+    id: u64,
+    kind: ChangeKind,
+    value: u64,
+    params: ProtocolParams,
+    eta: i64,
+) -> Instruction {
+    propose_change_with(
+        signer,
+        id,
+        ProposeChange {
+            kind: kind as u8,
+            value: value.to_le_bytes(),
+            eta: eta.to_le_bytes(),
+            params: SetParams::from(params),
+            min_deploy: [0; 8],
+            round_slots: [0; 8],
+            intermission_slots: [0; 8],
+        },
+    )
+}
+
+/// Proposes new parameters for an open table. Zero round or intermission lengths fall back to
+/// the Config defaults.
+pub fn propose_table_change(
+    signer: Pubkey,
+    id: u64,
+    table_id: u8,
+    min_deploy: u64,
+    round_slots: u64,
+    intermission_slots: u64,
+    eta: i64,
+) -> Instruction {
+    propose_change_with(
+        signer,
+        id,
+        ProposeChange {
+            kind: ChangeKind::Table as u8,
+            value: (table_id as u64).to_le_bytes(),
+            eta: eta.to_le_bytes(),
+            params: SetParams::from(ProtocolParams::default()),
+            min_deploy: min_deploy.to_le_bytes(),
+            round_slots: round_slots.to_le_bytes(),
+            intermission_slots: intermission_slots.to_le_bytes(),
+        },
+    )
+}
+
+fn propose_change_with(signer: Pubkey, id: u64, args: ProposeChange) -> Instruction {
+    let config_address = config_pda().0;
+    let pending_change_address = pending_change_pda(id).0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(pending_change_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: args.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, pending_change_info, proposer_info, board_info, ore_program] =
+
+/// Executes a pending change. `table_id` is the table a Table change updates, and
+/// DEFAULT_TABLE_ID for every other kind.
+pub fn execute_change(signer: Pubkey, id: u64, proposer: Pubkey, table_id: u8) -> Instruction {
+    let config_address = config_pda().0;
+    let pending_change_address = pending_change_pda(id).0;
+    let board_address = board_pda(table_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(pending_change_address, false),
+            AccountMeta::new(proposer, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: ExecuteChange {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, pending_change_info, proposer_info, board_info, ore_program] =
+
+pub fn cancel_change(signer: Pubkey, id: u64, proposer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let pending_change_address = pending_change_pda(id).0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(pending_change_address, false),
+            AccountMeta::new(proposer, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: CancelChange {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, role_transfer_info, system_program] = accounts
+
+/// Proposes a new fee collector, who must accept via accept_role_transfer.
+pub fn set_fee_collector(signer: Pubkey, fee_collector: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let role_transfer_address = role_transfer_pda(Role::FeeCollector as u8).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(role_transfer_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetFeeCollector {
+            fee_collector: fee_collector.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, board_info, round_info, system_program, ore_program] =
+
+pub fn set_table(
+    signer: Pubkey,
+    table_id: u8,
+    min_deploy: u64,
+    round_slots: u64,
+    intermission_slots: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let board_address = board_pda(table_id).0;
+    let round_address = round_pda(table_id, 0).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new(round_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: SetTable {
+            table_id,
+            min_deploy: min_deploy.to_le_bytes(),
+            round_slots: round_slots.to_le_bytes(),
+            intermission_slots: intermission_slots.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, system_program] =
+
+pub fn migrate_board(signer: Pubkey, table_id: u8) -> Instruction {
+    let board_address = board_pda(table_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: MigrateBoard { table_id }.to_bytes(),
     }
 }
 
-pub fn set_admin(signer: Pubkey, admin: Pubkey) -> Instruction {
+// let [signer_info, config_info, system_program] =
+
+pub fn migrate_config(signer: Pubkey) -> Instruction {
     let config_address = config_pda().0;
     Instruction {
         program_id: crate::ID,
@@ -349,13 +606,85 @@ pub fn set_admin(signer: Pubkey, admin: Pubkey) -> Instruction {
             AccountMeta::new(config_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
-        data: SetAdmin {
-            admin: admin.to_bytes(),
+        data: MigrateConfig {}.to_bytes(),
+    }
+}
+
+// let [signer_info, treasury_info, garage_vault_info, mint_info, system_program, token_program] =
+
+pub fn init_garage_vault(signer: Pubkey) -> Instruction {
+    let treasury_address = treasury_pda().0;
+    let garage_vault_address = garage_vault_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new(garage_vault_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: InitGarageVault {}.to_bytes(),
+    }
+}
+
+pub fn set_pause(signer: Pubkey, mask: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: SetPause {
+            mask: mask.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
+// let [signer_info, config_info, role_transfer_info, recipient_info, system_program] = accounts
+
+pub fn propose_role_transfer(signer: Pubkey, role: Role, recipient: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let role_transfer_address = role_transfer_pda(role as u8).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(role_transfer_address, false),
+            AccountMeta::new_readonly(recipient, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: ProposeRoleTransfer { role: role as u8 }.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, role_transfer_info, authority_info, board_info, ore_program] =
+
+pub fn accept_role_transfer(signer: Pubkey, role: Role, authority: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let role_transfer_address = role_transfer_pda(role as u8).0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(role_transfer_address, false),
+            AccountMeta::new(authority, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: AcceptRoleTransfer { role: role as u8 }.to_bytes(),
+    }
+}
+
 // DEPRECATED: ORE Staking SDK functions (replaced by Garage system)
 // let [signer_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
 
@@ -417,9 +746,9 @@ pub fn set_admin(signer: Pubkey, admin: Pubkey) -> Instruction {
 
 // let [signer_info, automation_info, miner_info, system_program] = accounts else {
 
-pub fn reload_sol(signer: Pubkey, authority: Pubkey) -> Instruction {
+pub fn reload_sol(signer: Pubkey, authority: Pubkey, table_id: u8) -> Instruction {
     let automation_address = automation_pda(authority).0;
-    let miner_address = miner_pda(authority).0;
+    let miner_address = miner_pda(authority, table_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -483,31 +812,41 @@ pub fn reload_sol(signer: Pubkey, authority: Pubkey) -> Instruction {
 //     }
 // }
 
+/// Replacing a board's pinned var requires `var_change`: the id and proposer of a ready
+/// Var change for the table.
 pub fn new_var(
     signer: Pubkey,
+    table_id: u8,
     provider: Pubkey,
     id: u64,
     commit: [u8; 32],
     samples: u64,
+    var_change: Option<(u64, Pubkey)>,
 ) -> Instruction {
-    let board_address = board_pda().0;
+    let board_address = board_pda(table_id).0;
     let config_address = config_pda().0;
     let var_address = entropy_api::state::var_pda(board_address, id).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(provider, false),
+        AccountMeta::new(var_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(entropy_api::ID, false),
+    ];
+    if let Some((change_id, proposer)) = var_change {
+        accounts.push(AccountMeta::new(pending_change_pda(change_id).0, false));
+        accounts.push(AccountMeta::new(proposer, false));
+    }
     Instruction {
         program_id: crate::ID,
-        accounts: vec![
-            AccountMeta::new(signer, true),
-            AccountMeta::new(board_address, false),
-            AccountMeta::new(config_address, false),
-            AccountMeta::new(provider, false),
-            AccountMeta::new(var_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(entropy_api::ID, false),
-        ],
+        accounts,
         data: NewVar {
             id: id.to_le_bytes(),
             commit: commit,
             samples: samples.to_le_bytes(),
+            table_id,
         }
         .to_bytes(),
     }
@@ -525,11 +864,15 @@ pub fn new_var(
 /// - Config PDA (stores admin and program settings)
 /// - Board PDA (game state - current round, slots, etc.)
 /// - Round 0 PDA (first game round)
+/// - House Garage PDA (fallback referrer)
+/// - Garage Vault PDA (FUEL token account for the Garage pool)
 pub fn initialize(signer: Pubkey) -> Instruction {
     let treasury_address = treasury_pda().0;
     let config_address = config_pda().0;
-    let board_address = board_pda().0;
-    let round_address = round_pda(0).0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    let round_address = round_pda(DEFAULT_TABLE_ID, 0).0;
+    let house_garage_address = garage_pda(HOUSE_ADDRESS).0;
+    let garage_vault_address = garage_vault_pda().0;
 
     Instruction {
         program_id: crate::ID,
@@ -539,9 +882,1093 @@ pub fn initialize(signer: Pubkey) -> Instruction {
             AccountMeta::new(config_address, false),
             AccountMeta::new(board_address, false),
             AccountMeta::new(round_address, false),
+            AccountMeta::new(house_garage_address, false),
+            AccountMeta::new(garage_vault_address, false),
+            AccountMeta::new_readonly(MINT_ADDRESS, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
             AccountMeta::new_readonly(crate::ID, false),
         ],
         data: Initialize {}.to_bytes(),
     }
 }
+
+// ============================================================================
+// Garage Instructions
+// ============================================================================
+
+/// A remaining account in a referrer chain walked by FuelUp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UplineAccount {
+    /// The Garage of a referrer wallet.
+    Garage(Pubkey),
+
+    /// The GarageForward record of a wallet whose Garage is closed.
+    Forward(Pubkey),
+}
+
+impl UplineAccount {
+    fn to_account_meta(&self) -> AccountMeta {
+        match self {
+            UplineAccount::Garage(authority) => AccountMeta::new(garage_pda(*authority).0, false),
+            UplineAccount::Forward(authority) => {
+                AccountMeta::new_readonly(garage_forward_pda(*authority).0, false)
+            }
+        }
+    }
+}
+
+// let [signer_info, payer_info, mint_info, sender_info, garage_info, referrer_info, referrer_garage_info, referral_ledger_info, treasury_info, config_info, garage_vault_info, team_tokens_info, board_info, system_program, token_program, ore_program, upline_infos @ ..] =
+
+/// Creates a FuelUp instruction to deposit FUEL into the signer's Garage.
+/// The referrer is only recorded on the first deposit. Repeat deposits must pass the
+/// Garage's stored referrer, whose Garage is credited.
+///
+/// `upline` lists the remaining accounts of the referrer chain, in walk order: the Garage of
+/// each level's stored referrer for levels 2 and up, preceded wherever a Garage is closed by
+/// its forward and, if one exists, the Garage it forwards to.
+pub fn fuel_up(
+    signer: Pubkey,
+    payer: Pubkey,
+    fee_collector: Pubkey,
+    referrer: Pubkey,
+    upline: &[UplineAccount],
+    amount: u64,
+) -> Instruction {
+    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let garage_address = garage_pda(signer).0;
+    let referrer_garage_address = garage_pda(referrer).0;
+    let referral_ledger_address = referral_ledger_pda(signer).0;
+    let treasury_address = treasury_pda().0;
+    let config_address = config_pda().0;
+    let garage_vault_address = garage_vault_pda().0;
+    let team_tokens_address = get_associated_token_address(&fee_collector, &MINT_ADDRESS);
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(payer, true),
+        AccountMeta::new(MINT_ADDRESS, false),
+        AccountMeta::new(sender_address, false),
+        AccountMeta::new(garage_address, false),
+        AccountMeta::new_readonly(referrer, false),
+        AccountMeta::new(referrer_garage_address, false),
+        AccountMeta::new(referral_ledger_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(garage_vault_address, false),
+        AccountMeta::new(team_tokens_address, false),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    accounts.extend(upline.iter().map(UplineAccount::to_account_meta));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: FuelUp {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, payer_info, mint_info, sender_info, garage_info, referral_code_info, referrer_garage_info, referral_ledger_info, treasury_info, config_info, garage_vault_info, team_tokens_info, board_info, system_program, token_program, ore_program, level_garage_infos @ ..] =
+
+/// Deposits into the signer's Garage using a referral code.
+/// On the first deposit, `referrer` must be the current owner of the code (read from the
+/// ReferralCode account). Repeat deposits must pass the Garage's stored referrer instead.
+pub fn fuel_up_with_code(
+    signer: Pubkey,
+    payer: Pubkey,
+    fee_collector: Pubkey,
+    code: [u8; REFERRAL_CODE_MAX_LEN],
+    referrer: Pubkey,
+    upline: &[UplineAccount],
+    amount: u64,
+) -> Instruction {
+    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let garage_address = garage_pda(signer).0;
+    let referral_code_address = referral_code_pda(code).0;
+    let referrer_garage_address = garage_pda(referrer).0;
+    let referral_ledger_address = referral_ledger_pda(signer).0;
+    let treasury_address = treasury_pda().0;
+    let config_address = config_pda().0;
+    let garage_vault_address = garage_vault_pda().0;
+    let team_tokens_address = get_associated_token_address(&fee_collector, &MINT_ADDRESS);
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(payer, true),
+        AccountMeta::new(MINT_ADDRESS, false),
+        AccountMeta::new(sender_address, false),
+        AccountMeta::new(garage_address, false),
+        AccountMeta::new_readonly(referral_code_address, false),
+        AccountMeta::new(referrer_garage_address, false),
+        AccountMeta::new(referral_ledger_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new_readonly(config_address, false),
+        AccountMeta::new(garage_vault_address, false),
+        AccountMeta::new(team_tokens_address, false),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    accounts.extend(upline.iter().map(UplineAccount::to_account_meta));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: FuelUpWithCode {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, authority_info, garage_info, garage_automation_info, config_info, treasury_info, garage_vault_info, mint_info, team_tokens_info, board_info, system_program, token_program, ore_program] =
+
+/// Boosts the authority's Garage. The signer is either the authority or its automation executor.
+pub fn boost(signer: Pubkey, authority: Pubkey, fee_collector: Pubkey, amount: u64) -> Instruction {
+    let garage_address = garage_pda(authority).0;
+    let garage_automation_address = garage_automation_pda(authority).0;
+    let config_address = config_pda().0;
+    let treasury_address = treasury_pda().0;
+    let garage_vault_address = garage_vault_pda().0;
+    let team_tokens_address = get_associated_token_address(&fee_collector, &MINT_ADDRESS);
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(authority, false),
+            AccountMeta::new(garage_address, false),
+            AccountMeta::new(garage_automation_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(garage_vault_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(team_tokens_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: Boost {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, authority_info, garage_info, garage_automation_info, config_info, treasury_info, garage_vault_info, recipient_info, mint_info, team_tokens_info, board_info, system_program, token_program, associated_token_program, ore_program] =
+
+/// Collects from the authority's Garage to the authority's wallet.
+/// The signer is either the authority or its automation executor.
+pub fn collect(
+    signer: Pubkey,
+    authority: Pubkey,
+    fee_collector: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let garage_address = garage_pda(authority).0;
+    let garage_automation_address = garage_automation_pda(authority).0;
+    let config_address = config_pda().0;
+    let treasury_address = treasury_pda().0;
+    let garage_vault_address = garage_vault_pda().0;
+    let recipient_address = get_associated_token_address(&authority, &MINT_ADDRESS);
+    let team_tokens_address = get_associated_token_address(&fee_collector, &MINT_ADDRESS);
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(authority, false),
+            AccountMeta::new(garage_address, false),
+            AccountMeta::new(garage_automation_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(garage_vault_address, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(team_tokens_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: Collect {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, garage_info, config_info, treasury_info, garage_vault_info, recipient_info, mint_info, team_tokens_info, board_info, system_program, token_program, associated_token_program, ore_program] =
+
+pub fn harvest(signer: Pubkey, fee_collector: Pubkey, boost_bps: u64, amount: u64) -> Instruction {
+    let garage_address = garage_pda(signer).0;
+    let config_address = config_pda().0;
+    let treasury_address = treasury_pda().0;
+    let garage_vault_address = garage_vault_pda().0;
+    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let team_tokens_address = get_associated_token_address(&fee_collector, &MINT_ADDRESS);
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(garage_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(garage_vault_address, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(team_tokens_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: Harvest {
+            boost_bps: boost_bps.to_le_bytes(),
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, garage_automation_info, executor_info, garage_info, system_program] =
+
+/// Opens, updates or closes (executor = Pubkey::default()) the signer's Garage automation.
+pub fn automate_garage(
+    signer: Pubkey,
+    executor: Pubkey,
+    deposit: u64,
+    fee: u64,
+    interval: u64,
+    boost_count: u64,
+    strategy: u8,
+) -> Instruction {
+    let garage_automation_address = garage_automation_pda(signer).0;
+    let garage_address = garage_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(garage_automation_address, false),
+            AccountMeta::new_readonly(executor, false),
+            AccountMeta::new(garage_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: AutomateGarage {
+            deposit: deposit.to_le_bytes(),
+            fee: fee.to_le_bytes(),
+            interval: interval.to_le_bytes(),
+            boost_count: boost_count.to_le_bytes(),
+            strategy,
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, garage_info, garage_transfer_info, recipient_info, system_program] =
+
+/// Proposes moving the signer's Garage to `recipient` (Pubkey::default() cancels).
+pub fn propose_garage_transfer(signer: Pubkey, recipient: Pubkey) -> Instruction {
+    let garage_address = garage_pda(signer).0;
+    let garage_transfer_address = garage_transfer_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(garage_address, false),
+            AccountMeta::new(garage_transfer_address, false),
+            AccountMeta::new_readonly(recipient, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: ProposeGarageTransfer {}.to_bytes(),
+    }
+}
+
+// let [signer_info, authority_info, garage_info, garage_transfer_info, garage_forward_info, recipient_garage_info, house_garage_info, board_info, system_program, ore_program, referrer_upline_infos @ ..] =
+
+/// Accepts a pending transfer of `authority`'s Garage to the signer.
+///
+/// If the signer is the Garage's referrer, `referrer_upline` must lead from the signer's
+/// forward to the Garage now holding the signer's referrals (see `fuel_up`).
+pub fn accept_garage_transfer(
+    signer: Pubkey,
+    authority: Pubkey,
+    referrer_upline: &[UplineAccount],
+) -> Instruction {
+    let garage_address = garage_pda(authority).0;
+    let garage_transfer_address = garage_transfer_pda(authority).0;
+    let garage_forward_address = garage_forward_pda(authority).0;
+    let recipient_garage_address = garage_pda(signer).0;
+    let house_garage_address = garage_pda(HOUSE_ADDRESS).0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(authority, false),
+        AccountMeta::new(garage_address, false),
+        AccountMeta::new(garage_transfer_address, false),
+        AccountMeta::new(garage_forward_address, false),
+        AccountMeta::new(recipient_garage_address, false),
+        AccountMeta::new(house_garage_address, false),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    accounts.extend(referrer_upline.iter().map(UplineAccount::to_account_meta));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: AcceptGarageTransfer {}.to_bytes(),
+    }
+}
+
+// let [signer_info, referral_code_info, garage_info, system_program] =
+
+/// Claims a normalized referral code (see ReferralCode::normalize) for the signer's Garage.
+pub fn claim_referral_code(signer: Pubkey, code: [u8; REFERRAL_CODE_MAX_LEN]) -> Instruction {
+    let referral_code_address = referral_code_pda(code).0;
+    let garage_address = garage_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(referral_code_address, false),
+            AccountMeta::new(garage_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: ClaimReferralCode { code }.to_bytes(),
+    }
+}
+
+// let [signer_info, referral_code_info] =
+
+pub fn release_referral_code(signer: Pubkey, code: [u8; REFERRAL_CODE_MAX_LEN]) -> Instruction {
+    let referral_code_address = referral_code_pda(code).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(referral_code_address, false),
+        ],
+        data: ReleaseReferralCode {}.to_bytes(),
+    }
+}
+
+// let [signer_info, referral_code_info, new_authority_info, new_authority_garage_info] =
+
+pub fn transfer_referral_code(
+    signer: Pubkey,
+    code: [u8; REFERRAL_CODE_MAX_LEN],
+    new_authority: Pubkey,
+) -> Instruction {
+    let referral_code_address = referral_code_pda(code).0;
+    let new_authority_garage_address = garage_pda(new_authority).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(referral_code_address, false),
+            AccountMeta::new_readonly(new_authority, false),
+            AccountMeta::new_readonly(new_authority_garage_address, false),
+        ],
+        data: TransferReferralCode {}.to_bytes(),
+    }
+}
+
+// let [signer_info, miner_info, garage_info, config_info, treasury_info, board_info, system_program, ore_program] =
+
+pub fn stash(signer: Pubkey, table_id: u8) -> Instruction {
+    let miner_address = miner_pda(signer, table_id).0;
+    let garage_address = garage_pda(signer).0;
+    let config_address = config_pda().0;
+    let treasury_address = treasury_pda().0;
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(garage_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: Stash {}.to_bytes(),
+    }
+}
+
+// let [signer_info, miner_info, config_info, treasury_info, mint_info, recipient_info, team_tokens_info, board_info, system_program, token_program, associated_token_program, ore_program] =
+
+pub fn claim_wallet(signer: Pubkey, fee_collector: Pubkey, table_id: u8) -> Instruction {
+    let miner_address = miner_pda(signer, table_id).0;
+    let config_address = config_pda().0;
+    let treasury_address = treasury_pda().0;
+    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let team_tokens_address = get_associated_token_address(&fee_collector, &MINT_ADDRESS);
+    let board_address = board_pda(DEFAULT_TABLE_ID).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(team_tokens_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ],
+        data: ClaimWallet {}.to_bytes(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{MINER, PAUSE_CLAIM, PAUSE_DEPLOY};
+
+    /// Asserts that the instruction accounts match the expected (address, is_signer, is_writable) layout.
+    fn assert_accounts(ix: &Instruction, expected: &[(Pubkey, bool, bool)]) {
+        assert_eq!(ix.accounts.len(), expected.len(), "Account count mismatch");
+        for (i, (meta, (address, is_signer, is_writable))) in
+            ix.accounts.iter().zip(expected.iter()).enumerate()
+        {
+            assert_eq!(meta.pubkey, *address, "Address mismatch at index {}", i);
+            assert_eq!(meta.is_signer, *is_signer, "Signer mismatch at index {}", i);
+            assert_eq!(meta.is_writable, *is_writable, "Writable mismatch at index {}", i);
+        }
+    }
+
+    #[test]
+    fn test_fuel_up_accounts() {
+        let signer = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+        let fee_collector = Pubkey::new_unique();
+        let ix = fuel_up(signer, payer, fee_collector, referrer, &[], 100);
+        assert_eq!(ix.program_id, crate::ID);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (payer, true, true),
+                (MINT_ADDRESS, false, true),
+                (get_associated_token_address(&signer, &MINT_ADDRESS), false, true),
+                (garage_pda(signer).0, false, true),
+                (referrer, false, false),
+                (garage_pda(referrer).0, false, true),
+                (referral_ledger_pda(signer).0, false, true),
+                (treasury_pda().0, false, true),
+                (config_pda().0, false, false),
+                (garage_vault_pda().0, false, true),
+                (get_associated_token_address(&fee_collector, &MINT_ADDRESS), false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (spl_token::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        let args = FuelUp::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(u64::from_le_bytes(args.amount), 100);
+
+        // Upline Garages are appended as remaining accounts, in level order.
+        let level_2 = Pubkey::new_unique();
+        let level_3 = Pubkey::new_unique();
+        let upline = [UplineAccount::Garage(level_2), UplineAccount::Garage(level_3)];
+        let ix = fuel_up(signer, payer, fee_collector, referrer, &upline, 100);
+        assert_eq!(ix.accounts.len(), 18);
+        assert_eq!(ix.accounts[16].pubkey, garage_pda(level_2).0);
+        assert!(ix.accounts[16].is_writable);
+        assert_eq!(ix.accounts[17].pubkey, garage_pda(level_3).0);
+        assert!(ix.accounts[17].is_writable);
+
+        // A transferred referrer is followed through its forward to the new owner's Garage.
+        let new_owner = Pubkey::new_unique();
+        let upline = [UplineAccount::Forward(referrer), UplineAccount::Garage(new_owner)];
+        let ix = fuel_up(signer, payer, fee_collector, referrer, &upline, 100);
+        assert_eq!(ix.accounts.len(), 18);
+        assert_eq!(ix.accounts[16].pubkey, garage_forward_pda(referrer).0);
+        assert!(!ix.accounts[16].is_writable);
+        assert_eq!(ix.accounts[17].pubkey, garage_pda(new_owner).0);
+        assert!(ix.accounts[17].is_writable);
+    }
+
+    #[test]
+    fn test_boost_accounts() {
+        let signer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let fee_collector = Pubkey::new_unique();
+        let ix = boost(signer, authority, fee_collector, 0);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (authority, false, false),
+                (garage_pda(authority).0, false, true),
+                (garage_automation_pda(authority).0, false, true),
+                (config_pda().0, false, false),
+                (treasury_pda().0, false, true),
+                (garage_vault_pda().0, false, true),
+                (MINT_ADDRESS, false, true),
+                (get_associated_token_address(&fee_collector, &MINT_ADDRESS), false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (spl_token::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::Boost as u8);
+        let args = Boost::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(u64::from_le_bytes(args.amount), 0);
+    }
+
+    #[test]
+    fn test_collect_accounts() {
+        let signer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let fee_collector = Pubkey::new_unique();
+        let ix = collect(signer, authority, fee_collector, 25);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (authority, false, false),
+                (garage_pda(authority).0, false, true),
+                (garage_automation_pda(authority).0, false, true),
+                (config_pda().0, false, false),
+                (treasury_pda().0, false, true),
+                (garage_vault_pda().0, false, true),
+                (get_associated_token_address(&authority, &MINT_ADDRESS), false, true),
+                (MINT_ADDRESS, false, true),
+                (get_associated_token_address(&fee_collector, &MINT_ADDRESS), false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (spl_token::ID, false, false),
+                (spl_associated_token_account::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::Collect as u8);
+        let args = Collect::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(u64::from_le_bytes(args.amount), 25);
+    }
+
+    #[test]
+    fn test_harvest_accounts() {
+        let signer = Pubkey::new_unique();
+        let fee_collector = Pubkey::new_unique();
+        let ix = harvest(signer, fee_collector, 2500, 100);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (garage_pda(signer).0, false, true),
+                (config_pda().0, false, false),
+                (treasury_pda().0, false, true),
+                (garage_vault_pda().0, false, true),
+                (get_associated_token_address(&signer, &MINT_ADDRESS), false, true),
+                (MINT_ADDRESS, false, true),
+                (get_associated_token_address(&fee_collector, &MINT_ADDRESS), false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (spl_token::ID, false, false),
+                (spl_associated_token_account::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::Harvest as u8);
+        let args = Harvest::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(u64::from_le_bytes(args.boost_bps), 2500);
+        assert_eq!(u64::from_le_bytes(args.amount), 100);
+    }
+
+    #[test]
+    fn test_automate_garage_accounts() {
+        let signer = Pubkey::new_unique();
+        let executor = Pubkey::new_unique();
+        let ix = automate_garage(signer, executor, 1_000, 10, 86400, 3, 1);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (garage_automation_pda(signer).0, false, true),
+                (executor, false, false),
+                (garage_pda(signer).0, false, true),
+                (system_program::ID, false, false),
+            ],
+        );
+        let args = AutomateGarage::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(u64::from_le_bytes(args.deposit), 1_000);
+        assert_eq!(u64::from_le_bytes(args.fee), 10);
+        assert_eq!(u64::from_le_bytes(args.interval), 86400);
+        assert_eq!(u64::from_le_bytes(args.boost_count), 3);
+        assert_eq!(args.strategy, 1);
+    }
+
+    #[test]
+    fn test_garage_transfer_accounts() {
+        let owner = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let ix = propose_garage_transfer(owner, recipient);
+        assert_accounts(
+            &ix,
+            &[
+                (owner, true, true),
+                (garage_pda(owner).0, false, true),
+                (garage_transfer_pda(owner).0, false, true),
+                (recipient, false, false),
+                (system_program::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::ProposeGarageTransfer as u8);
+
+        let ix = accept_garage_transfer(recipient, owner, &[]);
+        assert_accounts(
+            &ix,
+            &[
+                (recipient, true, true),
+                (owner, false, true),
+                (garage_pda(owner).0, false, true),
+                (garage_transfer_pda(owner).0, false, true),
+                (garage_forward_pda(owner).0, false, true),
+                (garage_pda(recipient).0, false, true),
+                (garage_pda(HOUSE_ADDRESS).0, false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::AcceptGarageTransfer as u8);
+    }
+
+    #[test]
+    fn test_referral_code_accounts() {
+        let signer = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let code = ReferralCode::normalize("pitcrew").unwrap();
+
+        let ix = claim_referral_code(owner, code);
+        assert_accounts(
+            &ix,
+            &[
+                (owner, true, true),
+                (referral_code_pda(code).0, false, true),
+                (garage_pda(owner).0, false, true),
+                (system_program::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::ClaimReferralCode as u8);
+        let args = ClaimReferralCode::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(args.code, code);
+
+        let fee_collector = Pubkey::new_unique();
+        let ix = fuel_up_with_code(signer, payer, fee_collector, code, owner, &[], 100);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (payer, true, true),
+                (MINT_ADDRESS, false, true),
+                (get_associated_token_address(&signer, &MINT_ADDRESS), false, true),
+                (garage_pda(signer).0, false, true),
+                (referral_code_pda(code).0, false, false),
+                (garage_pda(owner).0, false, true),
+                (referral_ledger_pda(signer).0, false, true),
+                (treasury_pda().0, false, true),
+                (config_pda().0, false, false),
+                (garage_vault_pda().0, false, true),
+                (get_associated_token_address(&fee_collector, &MINT_ADDRESS), false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (spl_token::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::FuelUpWithCode as u8);
+    }
+
+    #[test]
+    fn test_set_params_roundtrip() {
+        let signer = Pubkey::new_unique();
+        let mut params = ProtocolParams::default();
+        params.daily_rate_bps = 100;
+        params.whale_tax_rate_bps[9] = 6000;
+        let ix = propose_change(signer, 0, ChangeKind::Params, 0, params, 1_700_000_000);
+        assert_eq!(ix.data[0], OreInstruction::ProposeChange as u8);
+        let args = ProposeChange::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(args.params.params(), params);
+    }
+
+    #[test]
+    fn test_migrate_config_accounts() {
+        let signer = Pubkey::new_unique();
+        let ix = migrate_config(signer);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (config_pda().0, false, true),
+                (system_program::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::MigrateConfig as u8);
+    }
+
+    #[test]
+    fn test_init_garage_vault_accounts() {
+        let signer = Pubkey::new_unique();
+        let ix = init_garage_vault(signer);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (treasury_pda().0, false, false),
+                (garage_vault_pda().0, false, true),
+                (MINT_ADDRESS, false, false),
+                (system_program::ID, false, false),
+                (spl_token::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::InitGarageVault as u8);
+    }
+
+    #[test]
+    fn test_pending_change_accounts() {
+        let admin = Pubkey::new_unique();
+        let executor = Pubkey::new_unique();
+        let ix = propose_change(
+            admin,
+            7,
+            ChangeKind::BackstopCap,
+            1_000,
+            ProtocolParams::default(),
+            1_700_000_000,
+        );
+        assert_accounts(
+            &ix,
+            &[
+                (admin, true, true),
+                (config_pda().0, false, true),
+                (pending_change_pda(7).0, false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::ProposeChange as u8);
+        let args = ProposeChange::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(args.kind, ChangeKind::BackstopCap as u8);
+        assert_eq!(u64::from_le_bytes(args.value), 1_000);
+        assert_eq!(i64::from_le_bytes(args.eta), 1_700_000_000);
+        assert_eq!(args.params.params(), ProtocolParams::default());
+
+        // Anyone can execute; rent goes back to the proposer.
+        let ix = execute_change(executor, 7, admin, DEFAULT_TABLE_ID);
+        assert_accounts(
+            &ix,
+            &[
+                (executor, true, true),
+                (config_pda().0, false, true),
+                (pending_change_pda(7).0, false, true),
+                (admin, false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::ExecuteChange as u8);
+
+        // Table changes name the table and execute against its board.
+        let ix = propose_table_change(admin, 8, 2, 100_000_000, 75, 10, 1_700_000_000);
+        assert_eq!(ix.accounts[2].pubkey, pending_change_pda(8).0);
+        let args = ProposeChange::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(args.kind, ChangeKind::Table as u8);
+        assert_eq!(u64::from_le_bytes(args.value), 2);
+        assert_eq!(u64::from_le_bytes(args.min_deploy), 100_000_000);
+        assert_eq!(u64::from_le_bytes(args.round_slots), 75);
+        assert_eq!(u64::from_le_bytes(args.intermission_slots), 10);
+        let ix = execute_change(executor, 8, admin, 2);
+        assert_eq!(ix.accounts[4].pubkey, board_pda(2).0);
+        assert!(ix.accounts[4].is_writable);
+
+        let ix = cancel_change(admin, 7, admin);
+        assert!(!ix.accounts[1].is_writable);
+        assert_eq!(ix.accounts[2].pubkey, pending_change_pda(7).0);
+        assert_eq!(ix.data[0], OreInstruction::CancelChange as u8);
+
+        // Replacing a pinned var appends the Var change and its proposer.
+        let ix = new_var(admin, 2, executor, 1, [0; 32], 100, None);
+        assert_eq!(ix.accounts.len(), 7);
+        let ix = new_var(admin, 2, executor, 1, [0; 32], 100, Some((7, admin)));
+        assert_eq!(ix.accounts.len(), 9);
+        assert_eq!(ix.accounts[7].pubkey, pending_change_pda(7).0);
+        assert!(ix.accounts[7].is_writable);
+        assert_eq!(ix.accounts[8].pubkey, admin);
+        assert_eq!(ix.data[0], OreInstruction::NewVar as u8);
+    }
+
+    #[test]
+    fn test_role_transfer_accounts() {
+        let admin = Pubkey::new_unique();
+        let pauser = Pubkey::new_unique();
+        let ix = propose_role_transfer(admin, Role::Pauser, pauser);
+        assert_accounts(
+            &ix,
+            &[
+                (admin, true, true),
+                (config_pda().0, false, false),
+                (role_transfer_pda(Role::Pauser as u8).0, false, true),
+                (pauser, false, false),
+                (system_program::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::ProposeRoleTransfer as u8);
+        assert_eq!(ix.data[1], Role::Pauser as u8);
+
+        // Each role has its own proposal PDA.
+        assert_ne!(
+            role_transfer_pda(Role::Admin as u8).0,
+            role_transfer_pda(Role::Pauser as u8).0
+        );
+
+        let ix = accept_role_transfer(pauser, Role::Pauser, admin);
+        assert_accounts(
+            &ix,
+            &[
+                (pauser, true, true),
+                (config_pda().0, false, true),
+                (role_transfer_pda(Role::Pauser as u8).0, false, true),
+                (admin, false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::AcceptRoleTransfer as u8);
+    }
+
+    #[test]
+    fn test_fee_instructions() {
+        let admin = Pubkey::new_unique();
+        let fee_collector = Pubkey::new_unique();
+        let ix = set_fee_collector(admin, fee_collector);
+        assert_accounts(
+            &ix,
+            &[
+                (admin, true, true),
+                (config_pda().0, false, false),
+                (role_transfer_pda(Role::FeeCollector as u8).0, false, true),
+                (system_program::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::SetFeeCollector as u8);
+        let args = SetFeeCollector::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(Pubkey::new_from_array(args.fee_collector), fee_collector);
+    }
+
+    #[test]
+    fn test_checkpoint_accounts() {
+        let signer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let ix = checkpoint(signer, authority, 2, 7);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (board_pda(2).0, false, true),
+                (config_pda().0, false, false),
+                (miner_pda(authority, 2).0, false, true),
+                (round_pda(2, 7).0, false, true),
+                (treasury_pda().0, false, true),
+                (system_program::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::Checkpoint as u8);
+        let args = Checkpoint::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(args.table_id, 2);
+    }
+
+    #[test]
+    fn test_table_pdas() {
+        // The default table keeps the addresses it had before tables existed.
+        let authority = Pubkey::new_unique();
+        assert_eq!(
+            board_pda(DEFAULT_TABLE_ID).0,
+            Pubkey::find_program_address(&[BOARD], &crate::ID).0
+        );
+        assert_eq!(
+            round_pda(DEFAULT_TABLE_ID, 3).0,
+            Pubkey::find_program_address(&[ROUND, &3u64.to_le_bytes()], &crate::ID).0
+        );
+        assert_eq!(
+            miner_pda(authority, DEFAULT_TABLE_ID).0,
+            Pubkey::find_program_address(&[MINER, &authority.to_bytes()], &crate::ID).0
+        );
+
+        // Other tables are namespaced.
+        assert_ne!(board_pda(1).0, board_pda(DEFAULT_TABLE_ID).0);
+        assert_ne!(board_pda(1).0, board_pda(2).0);
+        assert_ne!(round_pda(1, 3).0, round_pda(DEFAULT_TABLE_ID, 3).0);
+        assert_ne!(round_pda(1, 3).0, round_pda(2, 3).0);
+        assert_ne!(miner_pda(authority, 1).0, miner_pda(authority, DEFAULT_TABLE_ID).0);
+
+        let signer = Pubkey::new_unique();
+        let var = entropy_api::state::var_pda(board_pda(1).0, 4).0;
+        let ix = deploy(signer, authority, 1, var, 1_000_000_000, 3, [true; 25]);
+        assert_eq!(ix.accounts[3].pubkey, board_pda(1).0);
+        assert_eq!(ix.accounts[5].pubkey, miner_pda(authority, 1).0);
+        assert_eq!(ix.accounts[6].pubkey, round_pda(1, 3).0);
+        assert_eq!(ix.accounts[9].pubkey, var);
+        let ix = reset(signer, authority, 1, var, 3, authority);
+        assert_eq!(ix.accounts[5].pubkey, round_pda(1, 3).0);
+        assert_eq!(ix.accounts[14].pubkey, var);
+        let args = Deploy::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(args.table_id, 1);
+
+        let ix = set_table(signer, 1, 100_000_000, 75, 0);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (config_pda().0, false, false),
+                (board_pda(1).0, false, true),
+                (round_pda(1, 0).0, false, true),
+                (system_program::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::SetTable as u8);
+        let args = SetTable::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(args.table_id, 1);
+        assert_eq!(u64::from_le_bytes(args.min_deploy), 100_000_000);
+        assert_eq!(u64::from_le_bytes(args.round_slots), 75);
+
+        let ix = migrate_board(signer, 1);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (board_pda(1).0, false, true),
+                (system_program::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::MigrateBoard as u8);
+        assert_eq!(MigrateBoard::try_from_bytes(&ix.data[1..]).unwrap().table_id, 1);
+    }
+
+    #[test]
+    fn test_deploy_multi() {
+        let signer = Pubkey::new_unique();
+        let var = Pubkey::new_unique();
+        let ix = deploy_multi(signer, 1, var, 3, &[(0, 500_000_000), (12, 100_000_000)]);
+        assert_eq!(ix.accounts.len(), 11);
+        assert_eq!(ix.accounts[9].pubkey, var);
+        assert_eq!(ix.accounts[1].pubkey, signer);
+        assert_eq!(ix.accounts[5].pubkey, miner_pda(signer, 1).0);
+        assert_eq!(ix.accounts[6].pubkey, round_pda(1, 3).0);
+        assert_eq!(ix.data[0], OreInstruction::DeployMulti as u8);
+
+        // Header, then one 9-byte entry per square.
+        let header_size = std::mem::size_of::<DeployMulti>();
+        let (header, entries) = ix.data[1..].split_at(header_size);
+        assert_eq!(DeployMulti::try_from_bytes(header).unwrap().table_id, 1);
+        assert_eq!(entries.len(), 2 * std::mem::size_of::<DeploySquare>());
+        let amounts = DeployMulti::amounts(entries).unwrap();
+        assert_eq!(amounts[0], 500_000_000);
+        assert_eq!(amounts[12], 100_000_000);
+        assert_eq!(amounts.iter().sum::<u64>(), 600_000_000);
+
+        // Out-of-range, duplicate, and zero-amount squares are rejected.
+        for squares in [
+            vec![(25, 1)],
+            vec![(3, 1), (3, 2)],
+            vec![(3, 0)],
+            vec![],
+        ] {
+            let ix = deploy_multi(signer, 0, var, 0, &squares);
+            assert!(DeployMulti::amounts(&ix.data[1 + header_size..]).is_err());
+        }
+        assert!(DeployMulti::amounts(&[0; 10]).is_err(), "Truncated entry should be rejected");
+    }
+
+    #[test]
+    fn test_pause_instructions() {
+        let pauser = Pubkey::new_unique();
+        let ix = set_pause(pauser, PAUSE_DEPLOY | PAUSE_CLAIM);
+        assert_accounts(
+            &ix,
+            &[
+                (pauser, true, true),
+                (config_pda().0, false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::SetPause as u8);
+        let args = SetPause::try_from_bytes(&ix.data[1..]).unwrap();
+        assert_eq!(u64::from_le_bytes(args.mask), PAUSE_DEPLOY | PAUSE_CLAIM);
+
+        let ix = claim_sol(pauser, DEFAULT_TABLE_ID);
+        assert_eq!(ix.accounts[2].pubkey, config_pda().0);
+        let ix = claim_ore(pauser, DEFAULT_TABLE_ID);
+        assert_eq!(ix.accounts[2].pubkey, config_pda().0);
+    }
+
+    #[test]
+    fn test_stash_accounts() {
+        let signer = Pubkey::new_unique();
+        let ix = stash(signer, DEFAULT_TABLE_ID);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (miner_pda(signer, DEFAULT_TABLE_ID).0, false, true),
+                (garage_pda(signer).0, false, true),
+                (config_pda().0, false, false),
+                (treasury_pda().0, false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::Stash as u8);
+    }
+
+    #[test]
+    fn test_claim_wallet_accounts() {
+        let signer = Pubkey::new_unique();
+        let fee_collector = Pubkey::new_unique();
+        let ix = claim_wallet(signer, fee_collector, DEFAULT_TABLE_ID);
+        assert_accounts(
+            &ix,
+            &[
+                (signer, true, true),
+                (miner_pda(signer, DEFAULT_TABLE_ID).0, false, true),
+                (config_pda().0, false, false),
+                (treasury_pda().0, false, true),
+                (MINT_ADDRESS, false, true),
+                (get_associated_token_address(&signer, &MINT_ADDRESS), false, true),
+                (get_associated_token_address(&fee_collector, &MINT_ADDRESS), false, true),
+                (board_pda(DEFAULT_TABLE_ID).0, false, true),
+                (system_program::ID, false, false),
+                (spl_token::ID, false, false),
+                (spl_associated_token_account::ID, false, false),
+                (crate::ID, false, false),
+            ],
+        );
+        assert_eq!(ix.data[0], OreInstruction::ClaimWallet as u8);
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::miner_pda;
+use crate::state::automation_pda;
 
 use super::OreAccount;
 
@@ -27,11 +27,56 @@ pub struct Automation {
     pub strategy: u64,
 
     /// The mask of squares this automation should deploy to if preferred strategy.
-    /// If strategy is Random, first bit is used to determine how many squares to deploy to.
+    /// If strategy is Random or Contrarian, first bit is used to determine how many squares to deploy to.
     pub mask: u64,
 
     /// Whether or not to auto-reload SOL winnings into the automation balance.
     pub reload: u64,
+
+    /// The most SOL the Budget strategy may deploy per round (0 = no cap).
+    pub round_budget: u64,
+
+    /// The most SOL the Budget strategy may deploy per day (0 = no cap).
+    pub daily_budget: u64,
+
+    /// The round `round_spent` was tracked for.
+    pub budget_round_id: u64,
+
+    /// The amount of SOL deployed during `budget_round_id`.
+    pub round_spent: u64,
+
+    /// The day (unix timestamp / ONE_DAY) `day_spent` was tracked for.
+    pub budget_day: u64,
+
+    /// The amount of SOL deployed during `budget_day`.
+    pub day_spent: u64,
+
+    /// The cumulative losses above which the StopLoss strategy stops deploying (0 = no limit).
+    pub stop_loss: u64,
+
+    /// The amount of SOL deployed since the automation was last configured.
+    pub total_spent: u64,
+
+    /// The amount of SOL won since the automation was last configured.
+    pub total_won: u64,
+
+    /// The miner's lifetime SOL rewards when last observed.
+    pub rewards_seen: u64,
+
+    /// Whether the StopLoss strategy has stopped this automation (cleared by Automate).
+    pub stopped: u64,
+
+    /// The number of rounds to play before closing (0 = no limit).
+    pub max_rounds: u64,
+
+    /// The number of rounds played since the automation was last configured.
+    pub rounds_played: u64,
+
+    /// The slot at which the automation closes (0 = never).
+    pub expires_at_slot: u64,
+
+    /// The table whose miner `rewards_seen` was observed on.
+    pub rewards_table_id: u64,
 }
 
 #[repr(u8)]
@@ -40,6 +85,29 @@ pub enum AutomationStrategy {
     Random = 0,
     Preferred = 1,
     Discretionary = 2,
+
+    /// Deploy to the N least-deployed squares of the round.
+    Contrarian = 3,
+
+    /// Deploy to the preferred mask, capped per round and per day.
+    Budget = 4,
+
+    /// Deploy to the preferred mask until cumulative losses exceed `stop_loss`.
+    StopLoss = 5,
+}
+
+/// Why an automation was closed.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum AutomationStopReason {
+    /// The balance could no longer cover a square and the executor fee.
+    Balance = 0,
+
+    /// `max_rounds` rounds were played.
+    MaxRounds = 1,
+
+    /// `expires_at_slot` was reached.
+    Expired = 2,
 }
 
 impl AutomationStrategy {
@@ -50,7 +118,135 @@ impl AutomationStrategy {
 
 impl Automation {
     pub fn pda(&self) -> (Pubkey, u8) {
-        miner_pda(self.authority)
+        automation_pda(self.authority)
+    }
+
+    /// The squares in the preferred mask.
+    pub fn preferred_squares(&self) -> [bool; 25] {
+        let mut squares = [false; 25];
+        for i in 0..25 {
+            squares[i] = (self.mask & (1 << i)) != 0;
+        }
+        squares
+    }
+
+    /// The `num_squares` squares with the least SOL deployed. Ties go to the lower index.
+    pub fn contrarian_squares(num_squares: u64, deployed: &[u64; 25]) -> [bool; 25] {
+        let mut squares = [false; 25];
+        for _ in 0..num_squares.min(25) {
+            let mut pick = None;
+            for i in 0..25 {
+                if !squares[i] && pick.map_or(true, |p: usize| deployed[i] < deployed[p]) {
+                    pick = Some(i);
+                }
+            }
+            if let Some(i) = pick {
+                squares[i] = true;
+            }
+        }
+        squares
+    }
+
+    /// The SOL the Budget strategy may still deploy in `round_id` on `day`.
+    pub fn budget_remaining(&self, round_id: u64, day: u64) -> u64 {
+        let mut remaining = u64::MAX;
+        if self.round_budget > 0 {
+            let spent = if self.budget_round_id == round_id {
+                self.round_spent
+            } else {
+                0
+            };
+            remaining = remaining.min(self.round_budget.saturating_sub(spent));
+        }
+        if self.daily_budget > 0 {
+            let spent = if self.budget_day == day {
+                self.day_spent
+            } else {
+                0
+            };
+            remaining = remaining.min(self.daily_budget.saturating_sub(spent));
+        }
+        remaining
+    }
+
+    /// The preferred squares, trimmed (highest index first) to what the budget still allows.
+    pub fn budget_squares(&self, round_id: u64, day: u64) -> [bool; 25] {
+        let mut squares = self.preferred_squares();
+        let affordable = match self.amount {
+            0 => 0,
+            amount => self.budget_remaining(round_id, day) / amount,
+        };
+        let mut selected = 0;
+        for square in squares.iter_mut() {
+            if *square {
+                if selected < affordable {
+                    selected += 1;
+                } else {
+                    *square = false;
+                }
+            }
+        }
+        squares
+    }
+
+    /// Record SOL deployed by this automation.
+    pub fn record_spend(&mut self, round_id: u64, day: u64, amount: u64) {
+        if self.budget_round_id != round_id {
+            self.budget_round_id = round_id;
+            self.round_spent = 0;
+        }
+        if self.budget_day != day {
+            self.budget_day = day;
+            self.day_spent = 0;
+        }
+        self.round_spent = self.round_spent.saturating_add(amount);
+        self.day_spent = self.day_spent.saturating_add(amount);
+        self.total_spent = self.total_spent.saturating_add(amount);
+    }
+
+    /// Record the lifetime SOL rewards of the miner on `table_id`, crediting anything new as won.
+    ///
+    /// Miners are per table, so a reading from a different table than the last one only
+    /// resets the baseline. Crediting it would count the other miner's history as new winnings.
+    pub fn record_rewards(&mut self, table_id: u8, lifetime_rewards_sol: u64) {
+        if self.rewards_table_id == table_id as u64 {
+            self.total_won = self
+                .total_won
+                .saturating_add(lifetime_rewards_sol.saturating_sub(self.rewards_seen));
+        }
+        self.rewards_table_id = table_id as u64;
+        self.rewards_seen = lifetime_rewards_sol;
+    }
+
+    /// The SOL lost since the automation was last configured.
+    pub fn losses(&self) -> u64 {
+        self.total_spent.saturating_sub(self.total_won)
+    }
+
+    /// Whether the automation has reached its expiry slot.
+    pub fn is_expired(&self, slot: u64) -> bool {
+        self.expires_at_slot > 0 && slot >= self.expires_at_slot
+    }
+
+    /// Whether the automation has played all of its rounds.
+    pub fn is_max_rounds_hit(&self) -> bool {
+        self.max_rounds > 0 && self.rounds_played >= self.max_rounds
+    }
+
+    /// The lifetime limit the automation has hit at `slot`, if any.
+    pub fn limit_hit(&self, slot: u64) -> Option<AutomationStopReason> {
+        if self.is_expired(slot) {
+            Some(AutomationStopReason::Expired)
+        } else if self.is_max_rounds_hit() {
+            Some(AutomationStopReason::MaxRounds)
+        } else {
+            None
+        }
+    }
+
+    /// Whether losses have exceeded the stop-loss threshold.
+    pub fn is_stop_loss_hit(&self) -> bool {
+        self.stop_loss > 0 && self.losses() > self.stop_loss
     }
 }
 
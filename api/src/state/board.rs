@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::consts::*;
 use crate::state::board_pda;
 
-use super::OreAccount;
+use super::{OreAccount, ProtocolParams};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
@@ -19,11 +20,51 @@ pub struct Board {
 
     /// The current epoch id.
     pub epoch_id: u64,
+
+    /// The table this board runs.
+    pub table_id: u64,
+
+    /// Minimum SOL per square at this table, in lamports (0 for no minimum).
+    pub min_deploy: u64,
+
+    /// Round length at this table, in slots (0 to use Config).
+    pub round_slots: u64,
+
+    /// Breather between rounds at this table, in slots (0 to use Config).
+    pub intermission_slots: u64,
+
+    /// The entropy var this board samples, set by NewVar.
+    pub var: Pubkey,
 }
 
 impl Board {
     pub fn pda(&self) -> (Pubkey, u8) {
-        board_pda()
+        board_pda(self.table_id as u8)
+    }
+
+    /// Round length at this table, falling back to the protocol default.
+    pub fn round_slots(&self, params: &ProtocolParams) -> u64 {
+        if self.round_slots == 0 {
+            params.round_slots
+        } else {
+            self.round_slots
+        }
+    }
+
+    /// Whether the table overrides are within the protocol bounds.
+    pub fn has_valid_params(&self) -> bool {
+        (self.round_slots == 0
+            || (MIN_ROUND_SLOTS..=MAX_ROUND_SLOTS).contains(&self.round_slots))
+            && self.intermission_slots <= MAX_INTERMISSION_SLOTS
+    }
+
+    /// Intermission at this table, falling back to the protocol default.
+    pub fn intermission_slots(&self, params: &ProtocolParams) -> u64 {
+        if self.intermission_slots == 0 {
+            params.intermission_slots
+        } else {
+            self.intermission_slots
+        }
     }
 }
 
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
+use crate::consts::*;
 use crate::state::config_pda;
 
 use super::OreAccount;
@@ -11,26 +12,209 @@ pub struct Config {
     /// The address that can update the config.
     pub admin: Pubkey,
 
-    /// Buffer a (placeholder)
-    pub buffer_a: [u8; 32],
+    /// The address that receives protocol fees.
+    pub fee_collector: Pubkey,
+
+    /// Minimum delay between proposing and executing a PendingChange, in seconds.
+    pub timelock_delay: u64,
+
+    /// Number of PendingChange proposals created (the next change id).
+    pub change_count: u64,
+
+    /// Bitmask of paused operations (see the PAUSE_* flags).
+    pub paused: u64,
 
     /// Buffer b (placeholder)
-    pub buffer_b: [u8; 32],
+    pub buffer_b: [u8; 8],
 
-    /// Buffer c (placeholder)
-    pub buffer_c: [u8; 32],
+    /// The address that can run buyback, wrap and liq.
+    pub treasury_operator: Pubkey,
 
-    /// Buffer d (placeholder)
-    pub buffer_d: [u8; 32],
+    /// The address that can pause the program.
+    pub pauser: Pubkey,
 
-    /// Buffer e (placeholder)
-    pub buffer_e: [u8; 8],
+    /// Maximum cumulative FUEL that Collect may mint when the Garage vault runs dry.
+    pub garage_backstop_cap: u64,
+
+    /// Layout version of the protocol parameters (CONFIG_VERSION once set).
+    pub version: u64,
+
+    /// Protocol economics, updatable by the admin via a timelocked ProposeChange.
+    pub params: ProtocolParams,
 }
 
 impl Config {
     pub fn pda() -> (Pubkey, u8) {
         config_pda()
     }
+
+    /// Whether any of the given pause flags is set.
+    pub fn is_paused(&self, flags: u64) -> bool {
+        self.paused & flags != 0
+    }
+
+    /// The address currently holding a role.
+    pub fn role(&self, role: Role) -> Pubkey {
+        match role {
+            Role::Admin => self.admin,
+            Role::TreasuryOperator => self.treasury_operator,
+            Role::Pauser => self.pauser,
+            Role::FeeCollector => self.fee_collector,
+        }
+    }
+
+    /// Whether `address` holds `role`. An unset role is held by no one.
+    pub fn has_role(&self, role: Role, address: &Pubkey) -> bool {
+        let holder = self.role(role);
+        holder != Pubkey::default() && holder == *address
+    }
+
+    /// Fill roles left unset by Configs created before the role existed.
+    pub fn backfill_roles(&mut self) {
+        if self.fee_collector == Pubkey::default() {
+            self.fee_collector = ADMIN_FEE_COLLECTOR;
+        }
+        if self.treasury_operator == Pubkey::default() {
+            self.treasury_operator = BURY_AUTHORITY;
+        }
+        if self.pauser == Pubkey::default() {
+            self.pauser = ADMIN_ADDRESS;
+        }
+    }
+
+    /// Assign a role to a new address.
+    pub fn set_role(&mut self, role: Role, address: Pubkey) {
+        match role {
+            Role::Admin => self.admin = address,
+            Role::TreasuryOperator => self.treasury_operator = address,
+            Role::Pauser => self.pauser = address,
+            Role::FeeCollector => self.fee_collector = address,
+        }
+    }
+}
+
+/// Authorities stored in Config. Each is transferred via ProposeRoleTransfer/AcceptRoleTransfer.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum Role {
+    /// Updates params and manages the other roles.
+    Admin = 0,
+
+    /// Runs buyback, wrap and liq.
+    TreasuryOperator = 1,
+
+    /// Pauses and unpauses the program.
+    Pauser = 2,
+
+    /// Receives protocol fees.
+    FeeCollector = 3,
+}
+
+/// Protocol economics stored on-chain so they can change without a program upgrade.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ProtocolParams {
+    /// Daily Garage yield rate, in basis points.
+    pub daily_rate_bps: u64,
+
+    /// Garage max payout, as a percentage of total deposited.
+    pub max_payout_mult: u64,
+
+    /// Base Collect tax, in basis points of the withdrawn amount.
+    pub collect_tax_bps: u64,
+
+    /// Whale tax thresholds, in basis points of the total outstanding Garage obligation.
+    pub whale_tax_threshold_bps: [u64; WHALE_TAX_TIERS],
+
+    /// Whale tax rates for each threshold, in basis points.
+    pub whale_tax_rate_bps: [u64; WHALE_TAX_TIERS],
+
+    /// Sprint team fee, in basis points of wagered SOL.
+    pub sprint_team_fee_bps: u64,
+
+    /// Sprint buyback fee sent to the treasury, in basis points of wagered SOL.
+    pub sprint_buyback_fee_bps: u64,
+
+    /// Lamports a miner pre-pays for bots to checkpoint them.
+    pub checkpoint_fee: u64,
+
+    /// Length of a Sprint round, in slots.
+    pub round_slots: u64,
+
+    /// Breather between Sprint rounds, in slots.
+    pub intermission_slots: u64,
+
+    /// Slots after a round ends during which miners may checkpoint its rewards.
+    pub claim_expiry_slots: u64,
+
+    /// Fuel Up referral fee per referrer level, in basis points of the deposit.
+    /// Any part of FUEL_UP_REF_BPS not split across levels goes to the pool.
+    pub ref_level_bps: [u64; REFERRAL_LEVELS],
+}
+
+impl Default for ProtocolParams {
+    fn default() -> Self {
+        Self {
+            daily_rate_bps: DAILY_RATE_BPS,
+            max_payout_mult: MAX_PAYOUT_MULT,
+            collect_tax_bps: COLLECT_TAX_BPS,
+            whale_tax_threshold_bps: WHALE_TAX_THRESHOLD_BPS,
+            whale_tax_rate_bps: WHALE_TAX_RATE_BPS,
+            sprint_team_fee_bps: SPRINT_TEAM_FEE_BPS,
+            sprint_buyback_fee_bps: SPRINT_BUYBACK_FEE_BPS,
+            checkpoint_fee: CHECKPOINT_FEE,
+            round_slots: ROUND_SLOTS,
+            intermission_slots: INTERMISSION_SLOTS,
+            claim_expiry_slots: ONE_DAY_SLOTS,
+            ref_level_bps: FUEL_UP_REF_LEVEL_BPS,
+        }
+    }
+}
+
+impl ProtocolParams {
+    /// Returns true if every parameter is within its allowed bounds.
+    pub fn is_valid(&self) -> bool {
+        (1..=MAX_DAILY_RATE_BPS).contains(&self.daily_rate_bps)
+            && (MIN_PAYOUT_MULT..=MAX_PAYOUT_MULT_LIMIT).contains(&self.max_payout_mult)
+            && (COLLECT_TEAM_BPS..=MAX_COLLECT_TAX_BPS).contains(&self.collect_tax_bps)
+            && self
+                .whale_tax_threshold_bps
+                .windows(2)
+                .all(|w| w[0] < w[1])
+            && self.whale_tax_threshold_bps[0] > 0
+            && self.whale_tax_threshold_bps[WHALE_TAX_TIERS - 1] <= DENOMINATOR_BPS
+            && self.whale_tax_rate_bps.windows(2).all(|w| w[0] <= w[1])
+            && self.whale_tax_rate_bps[WHALE_TAX_TIERS - 1] <= MAX_WHALE_TAX_BPS
+            && self.sprint_team_fee_bps <= MAX_SPRINT_TEAM_FEE_BPS
+            && self.sprint_team_fee_bps + self.sprint_buyback_fee_bps
+                <= MAX_SPRINT_PROTOCOL_FEE_BPS
+            && self.checkpoint_fee <= MAX_CHECKPOINT_FEE
+            && (MIN_ROUND_SLOTS..=MAX_ROUND_SLOTS).contains(&self.round_slots)
+            && self.intermission_slots <= MAX_INTERMISSION_SLOTS
+            && (MIN_CLAIM_EXPIRY_SLOTS..=MAX_CLAIM_EXPIRY_SLOTS).contains(&self.claim_expiry_slots)
+            && self.ref_level_bps.iter().all(|bps| *bps <= FUEL_UP_REF_BPS)
+            && self.ref_level_bps.iter().sum::<u64>() <= FUEL_UP_REF_BPS
+    }
+
+    /// The part of FUEL_UP_REF_BPS not split across referrer levels, in basis points.
+    pub fn ref_unallocated_bps(&self) -> u64 {
+        FUEL_UP_REF_BPS.saturating_sub(self.ref_level_bps.iter().sum::<u64>())
+    }
+
+    /// Slots before a round expires during which anyone may checkpoint a miner for the bot fee.
+    pub fn checkpoint_window_slots(&self) -> u64 {
+        self.claim_expiry_slots / 2
+    }
+
+    /// Calculate whale tax in BPS using this config's schedule.
+    pub fn whale_tax_bps(&self, user_balance: u64, total_tvl: u64) -> u64 {
+        calculate_whale_tax_bps_with(
+            user_balance,
+            total_tvl,
+            &self.whale_tax_threshold_bps,
+            &self.whale_tax_rate_bps,
+        )
+    }
 }
 
 account!(OreAccount, Config);
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use steel::*;
 
 use crate::consts::*;
+use crate::event::{NewCycleEvent, OreEvent};
 use crate::state::garage_pda;
 
 use super::OreAccount;
@@ -32,14 +33,30 @@ pub struct Garage {
     /// Account creation timestamp.
     pub created_at: i64,
 
-    /// Count of direct referrals.
+    /// Count of direct referrals (level 1).
     pub direct_referrals: u32,
 
-    /// Padding for alignment.
-    pub _padding: u32,
+    /// Whether this Garage's outstanding obligation is counted in
+    /// Treasury.total_garage_outstanding (0 for Garages that predate it).
+    pub outstanding_tracked: u32,
 
     /// Total FUEL earned from referrals over lifetime.
     pub lifetime_ref_earnings: u64,
+
+    /// Number of completed (exhausted and restarted) cycles.
+    pub cycle: u64,
+
+    /// Sum of total_deposited across completed cycles.
+    pub lifetime_deposited: u64,
+
+    /// Sum of total_claimed across completed cycles.
+    pub lifetime_claimed: u64,
+
+    /// Count of referrals at each level of the referrer chain.
+    pub level_referrals: [u64; REFERRAL_LEVELS],
+
+    /// Total FUEL earned from referrals at each level of the referrer chain.
+    pub level_ref_earnings: [u64; REFERRAL_LEVELS],
 }
 
 impl Garage {
@@ -49,41 +66,91 @@ impl Garage {
     }
 
     /// Check if account has reached max payout (exhausted).
+    /// A garage with no deposits in its current cycle is not exhausted.
     pub fn is_exhausted(&self) -> bool {
-        self.total_claimed >= self.max_payout
+        self.max_payout > 0 && self.total_claimed >= self.max_payout
+    }
+
+    /// Archive the completed cycle into lifetime counters and reset the position.
+    /// Referrer and referral stats carry over into the new cycle.
+    /// Returns the event describing the archived cycle.
+    pub fn start_new_cycle(&mut self, now: i64) -> NewCycleEvent {
+        let cycle_deposited = self.total_deposited;
+        let cycle_claimed = self.total_claimed;
+        self.lifetime_deposited = self.lifetime_deposited.saturating_add(self.total_deposited);
+        self.lifetime_claimed = self.lifetime_claimed.saturating_add(self.total_claimed);
+        self.cycle = self.cycle.saturating_add(1);
+        self.total_deposited = 0;
+        self.total_claimed = 0;
+        self.max_payout = 0;
+        self.last_action_at = now;
+        NewCycleEvent {
+            disc: OreEvent::NewCycle as u64,
+            authority: self.authority,
+            cycle: self.cycle,
+            cycle_deposited,
+            cycle_claimed,
+            lifetime_deposited: self.lifetime_deposited,
+            lifetime_claimed: self.lifetime_claimed,
+            ts: now,
+        }
+    }
+
+    /// Outstanding obligation of this garage (remaining payout).
+    /// This is the measure tracked in Treasury.total_garage_outstanding.
+    pub fn outstanding(&self) -> u64 {
+        self.max_payout.saturating_sub(self.total_claimed)
     }
 
     /// Calculate available rewards based on time elapsed.
     /// Returns the amount of FUEL that can be claimed.
-    pub fn calculate_available(&self, now: i64) -> u64 {
+    ///
+    /// Accrual is continuous (per second), so claiming at any point consumes
+    /// exactly the yield earned since `last_action_at` with no partial-day loss.
+    /// `daily_rate_bps` is the configured daily yield rate (ProtocolParams).
+    pub fn calculate_available(&self, now: i64, daily_rate_bps: u64) -> u64 {
         // If exhausted, no rewards available
         if self.is_exhausted() {
             return 0;
         }
 
-        let seconds_elapsed = now.saturating_sub(self.last_action_at) as u64;
-        let days_elapsed = seconds_elapsed / (ONE_DAY as u64);
+        let seconds_elapsed = now.saturating_sub(self.last_action_at).max(0) as u128;
 
-        // 1.5% daily = total_deposited * days * 150 / 10000
-        // Using checked arithmetic as per project conventions
-        let accrued = self
-            .total_deposited
-            .checked_mul(days_elapsed)
-            .and_then(|v| v.checked_mul(DAILY_RATE_BPS))
-            .and_then(|v| v.checked_div(DENOMINATOR_BPS))
-            .unwrap_or(0);
+        // Daily rate, prorated per second:
+        // total_deposited * seconds * daily_rate_bps / (10000 * 86400)
+        // Computed in u128 so large deposits over long periods cannot overflow.
+        let accrued = (self.total_deposited as u128)
+            .checked_mul(seconds_elapsed)
+            .and_then(|v| v.checked_mul(daily_rate_bps as u128))
+            .and_then(|v| v.checked_div(DENOMINATOR_BPS as u128 * ONE_DAY as u128))
+            .map(|v| v.min(u64::MAX as u128) as u64)
+            .unwrap_or(u64::MAX);
 
         // Cap at remaining payout
-        let remaining = self.max_payout.saturating_sub(self.total_claimed);
-        accrued.min(remaining)
+        accrued.min(self.outstanding())
+    }
+
+    /// Reset the accrual clock after an action that consumed rewards at `now`.
+    /// Rewards that were available but left unclaimed are carried forward by
+    /// backdating `last_action_at`, so partial Boost/Collect never loses accrual.
+    /// Must be called after `total_deposited` reflects any changes from the same action.
+    pub fn settle(&mut self, now: i64, unclaimed: u64, daily_rate_bps: u64) {
+        let rate = (self.total_deposited as u128) * (daily_rate_bps as u128);
+        let carry_seconds = if unclaimed == 0 || rate == 0 {
+            0
+        } else {
+            // Round up so the carried accrual is never less than what was left unclaimed.
+            ((unclaimed as u128) * (DENOMINATOR_BPS as u128) * (ONE_DAY as u128)).div_ceil(rate)
+        };
+        self.last_action_at = now.saturating_sub(carry_seconds.min(i64::MAX as u128) as i64);
     }
 
     /// Update max_payout when deposits or boosts occur.
-    /// max_payout = total_deposited * 365 / 100 = total_deposited * MAX_PAYOUT_MULT / 100
-    pub fn update_max_payout(&mut self) {
+    /// max_payout = total_deposited * max_payout_mult / 100 (365% by default)
+    pub fn update_max_payout(&mut self, max_payout_mult: u64) {
         self.max_payout = self
             .total_deposited
-            .checked_mul(MAX_PAYOUT_MULT)
+            .checked_mul(max_payout_mult)
             .and_then(|v| v.checked_div(100))
             .unwrap_or(u64::MAX);
     }
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::garage_automation_pda;
+
+use super::OreAccount;
+
+/// GarageAutomation lets a keeper Boost (and optionally Collect) a user's Garage on a schedule.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct GarageAutomation {
+    /// The authority of this automation account (Garage owner).
+    pub authority: Pubkey,
+
+    /// The executor of this automation account.
+    pub executor: Pubkey,
+
+    /// The amount of SOL this automation has left to pay executor fees.
+    pub balance: u64,
+
+    /// The amount of SOL the executor should receive per execution.
+    pub fee: u64,
+
+    /// The strategy this automation uses.
+    pub strategy: u64,
+
+    /// Minimum seconds between executions.
+    pub interval: u64,
+
+    /// Number of boosts before a collect (BoostThenCollect strategy).
+    pub boost_count: u64,
+
+    /// Number of boosts executed since the last collect.
+    pub boosts_since_collect: u64,
+
+    /// Timestamp of the last keeper execution.
+    pub last_executed_at: i64,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum GarageAutomationStrategy {
+    /// Boost every interval.
+    AutoBoost = 0,
+
+    /// Boost `boost_count` times, then collect once, and repeat.
+    BoostThenCollect = 1,
+}
+
+impl GarageAutomationStrategy {
+    pub fn from_u64(value: u64) -> Self {
+        Self::try_from(value as u8).unwrap()
+    }
+}
+
+impl GarageAutomation {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        garage_automation_pda(self.authority)
+    }
+
+    /// Whether the interval since the last execution has elapsed.
+    pub fn is_due(&self, now: i64) -> bool {
+        now >= self.last_executed_at.saturating_add(self.interval as i64)
+    }
+
+    /// Whether the policy allows a keeper Boost at `now`.
+    pub fn can_boost(&self, now: i64) -> bool {
+        self.is_due(now)
+            && match GarageAutomationStrategy::from_u64(self.strategy) {
+                GarageAutomationStrategy::AutoBoost => true,
+                GarageAutomationStrategy::BoostThenCollect => {
+                    self.boosts_since_collect < self.boost_count
+                }
+            }
+    }
+
+    /// Whether the policy allows a keeper Collect at `now`.
+    pub fn can_collect(&self, now: i64) -> bool {
+        self.is_due(now)
+            && match GarageAutomationStrategy::from_u64(self.strategy) {
+                GarageAutomationStrategy::AutoBoost => false,
+                GarageAutomationStrategy::BoostThenCollect => {
+                    self.boosts_since_collect >= self.boost_count
+                }
+            }
+    }
+
+    /// Record a keeper Boost.
+    pub fn record_boost(&mut self, now: i64) {
+        self.last_executed_at = now;
+        self.boosts_since_collect = self.boosts_since_collect.saturating_add(1);
+    }
+
+    /// Record a keeper Collect.
+    pub fn record_collect(&mut self, now: i64) {
+        self.last_executed_at = now;
+        self.boosts_since_collect = 0;
+    }
+}
+
+account!(OreAccount, GarageAutomation);
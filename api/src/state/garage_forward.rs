@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::garage_forward_pda;
+
+use super::OreAccount;
+
+/// GarageForward records where a transferred Garage position moved to.
+///
+/// Referees keep the previous owner as their stored referrer, so FuelUp follows this record
+/// from the closed Garage to the recipient's Garage when paying referral shares.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct GarageForward {
+    /// The previous owner of the Garage.
+    pub authority: Pubkey,
+
+    /// The owner the Garage was transferred to.
+    pub recipient: Pubkey,
+
+    /// Timestamp of the transfer.
+    pub created_at: i64,
+}
+
+impl GarageForward {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        garage_forward_pda(self.authority)
+    }
+}
+
+account!(OreAccount, GarageForward);
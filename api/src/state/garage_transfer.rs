@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::garage_transfer_pda;
+
+use super::OreAccount;
+
+/// GarageTransfer is a pending proposal to move a Garage position to a new owner.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct GarageTransfer {
+    /// The current owner of the Garage.
+    pub authority: Pubkey,
+
+    /// The proposed new owner, who must accept the transfer.
+    pub recipient: Pubkey,
+
+    /// Timestamp the transfer was proposed.
+    pub created_at: i64,
+}
+
+impl GarageTransfer {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        garage_transfer_pda(self.authority)
+    }
+}
+
+account!(OreAccount, GarageTransfer);
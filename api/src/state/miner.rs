@@ -55,8 +55,8 @@ pub struct Miner {
 }
 
 impl Miner {
-    pub fn pda(&self) -> (Pubkey, u8) {
-        miner_pda(self.authority)
+    pub fn pda(&self, table_id: u8) -> (Pubkey, u8) {
+        miner_pda(self.authority, table_id)
     }
 
     pub fn claim_ore(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
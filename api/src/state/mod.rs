@@ -2,7 +2,14 @@ mod automation;
 mod board;
 mod config;
 mod garage;
+mod garage_automation;
+mod garage_forward;
+mod garage_transfer;
 mod miner;
+mod pending_change;
+mod referral_code;
+mod referral_ledger;
+mod role_transfer;
 mod round;
 // DEPRECATED: ORE Staking state (replaced by Garage system)
 // mod stake;
@@ -12,7 +19,14 @@ pub use automation::*;
 pub use board::*;
 pub use config::*;
 pub use garage::*;
+pub use garage_automation::*;
+pub use garage_forward::*;
+pub use garage_transfer::*;
 pub use miner::*;
+pub use pending_change::*;
+pub use referral_code::*;
+pub use referral_ledger::*;
+pub use role_transfer::*;
 pub use round::*;
 // pub use stake::*;
 pub use treasury::*;
@@ -33,26 +47,49 @@ pub enum OreAccount {
     // Stake = 108,
     Round = 109,
     Garage = 110,
+    GarageAutomation = 111,
+    GarageTransfer = 112,
+    ReferralCode = 113,
+    ReferralLedger = 114,
+    PendingChange = 115,
+    RoleTransfer = 116,
+    GarageForward = 117,
 }
 
 pub fn automation_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[AUTOMATION, &authority.to_bytes()], &crate::ID)
 }
 
-pub fn board_pda() -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[BOARD], &crate::ID)
+/// Extra PDA seed namespacing a table's Board, Round and Miner accounts.
+/// The default table adds no seed, so its accounts keep the addresses they had before tables.
+pub fn table_seed(table_id: &u8) -> &[u8] {
+    if *table_id == DEFAULT_TABLE_ID {
+        &[]
+    } else {
+        std::slice::from_ref(table_id)
+    }
+}
+
+pub fn board_pda(table_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOARD, table_seed(&table_id)], &crate::ID)
 }
 
 pub fn config_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[CONFIG], &crate::ID)
 }
 
-pub fn miner_pda(authority: Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[MINER, &authority.to_bytes()], &crate::ID)
+pub fn miner_pda(authority: Pubkey, table_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MINER, &authority.to_bytes(), table_seed(&table_id)],
+        &crate::ID,
+    )
 }
 
-pub fn round_pda(id: u64) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[ROUND, &id.to_le_bytes()], &crate::ID)
+pub fn round_pda(table_id: u8, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ROUND, table_seed(&table_id), &id.to_le_bytes()],
+        &crate::ID,
+    )
 }
 
 // DEPRECATED: ORE Staking PDA (replaced by Garage system)
@@ -64,6 +101,38 @@ pub fn garage_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[GARAGE, &authority.to_bytes()], &crate::ID)
 }
 
+pub fn garage_automation_pda(authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GARAGE_AUTOMATION, &authority.to_bytes()], &crate::ID)
+}
+
+pub fn garage_transfer_pda(authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GARAGE_TRANSFER, &authority.to_bytes()], &crate::ID)
+}
+
+pub fn garage_forward_pda(authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GARAGE_FORWARD, &authority.to_bytes()], &crate::ID)
+}
+
+pub fn referral_code_pda(code: [u8; REFERRAL_CODE_MAX_LEN]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRAL_CODE, &code], &crate::ID)
+}
+
+pub fn referral_ledger_pda(referee: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRAL_LEDGER, &referee.to_bytes()], &crate::ID)
+}
+
+pub fn pending_change_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_CHANGE, &id.to_le_bytes()], &crate::ID)
+}
+
+pub fn role_transfer_pda(role: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROLE_TRANSFER, &[role]], &crate::ID)
+}
+
+pub fn garage_vault_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GARAGE_VAULT], &crate::ID)
+}
+
 pub fn treasury_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[TREASURY], &crate::ID)
 }
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::consts::{CONFIG_VERSION, MAX_TIMELOCK_DELAY, MIN_TIMELOCK_DELAY};
+use crate::state::pending_change_pda;
+
+use super::{Board, Config, OreAccount, ProtocolParams};
+
+/// PendingChange is a timelocked admin change to the Config or table parameters.
+/// Anyone may execute it once `eta` has passed; the admin may cancel it until then.
+/// Var changes are not executed but consumed by NewVar when it replaces a pinned var.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct PendingChange {
+    /// The id of the change (Config.change_count at proposal time).
+    pub id: u64,
+
+    /// The kind of change (see ChangeKind).
+    pub kind: u64,
+
+    /// The admin who proposed the change, refunded the account rent on close.
+    pub proposer: Pubkey,
+
+    /// The new value (BackstopCap and TimelockDelay changes, or the table id of Var and Table
+    /// changes).
+    pub value: u64,
+
+    /// The new protocol parameters (Params changes).
+    pub params: ProtocolParams,
+
+    /// The new minimum SOL per square of the table (Table changes).
+    pub min_deploy: u64,
+
+    /// The new round length override of the table (Table changes).
+    pub round_slots: u64,
+
+    /// The new intermission override of the table (Table changes).
+    pub intermission_slots: u64,
+
+    /// Timestamp the change was proposed.
+    pub created_at: i64,
+
+    /// Earliest timestamp the change may be executed.
+    pub eta: i64,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum ChangeKind {
+    /// Replace the protocol parameters.
+    Params = 0,
+
+    /// Replace the Garage backstop cap.
+    BackstopCap = 1,
+
+    /// Replace the timelock delay.
+    TimelockDelay = 2,
+
+    /// Allow NewVar to replace the var pinned to a table.
+    Var = 3,
+
+    /// Replace the minimum deploy, round length and intermission of an open table.
+    Table = 4,
+}
+
+impl PendingChange {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        pending_change_pda(self.id)
+    }
+
+    /// Whether the change carries a valid payload for its kind.
+    pub fn is_valid(&self) -> bool {
+        match ChangeKind::try_from(self.kind as u8) {
+            Ok(ChangeKind::Params) => self.params.is_valid(),
+            Ok(ChangeKind::BackstopCap) => true,
+            Ok(ChangeKind::TimelockDelay) => {
+                (MIN_TIMELOCK_DELAY..=MAX_TIMELOCK_DELAY).contains(&self.value)
+            }
+            Ok(ChangeKind::Var) => self.value <= u8::MAX as u64,
+            Ok(ChangeKind::Table) => {
+                let mut board = Board::zeroed();
+                self.apply_table(&mut board);
+                self.value <= u8::MAX as u64 && board.has_valid_params()
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Whether the change is applied by ExecuteChange rather than consumed by another instruction.
+    pub fn is_executable(&self) -> bool {
+        self.kind != ChangeKind::Var as u64
+    }
+
+    /// Whether the timelock has elapsed at `now`.
+    pub fn is_ready(&self, now: i64) -> bool {
+        now >= self.eta
+    }
+
+    /// Apply the change to the config. Table changes are applied to their Board by apply_table.
+    pub fn apply(&self, config: &mut Config) {
+        match ChangeKind::try_from(self.kind as u8) {
+            Ok(ChangeKind::Params) => {
+                config.version = CONFIG_VERSION;
+                config.params = self.params;
+            }
+            Ok(ChangeKind::BackstopCap) => config.garage_backstop_cap = self.value,
+            Ok(ChangeKind::TimelockDelay) => config.timelock_delay = self.value,
+            Ok(ChangeKind::Var) | Ok(ChangeKind::Table) | Err(_) => {}
+        }
+    }
+
+    /// Apply a Table change to the board of its table.
+    pub fn apply_table(&self, board: &mut Board) {
+        if self.kind == ChangeKind::Table as u64 {
+            board.min_deploy = self.min_deploy;
+            board.round_slots = self.round_slots;
+            board.intermission_slots = self.intermission_slots;
+        }
+    }
+}
+
+account!(OreAccount, PendingChange);
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::consts::*;
+use crate::state::referral_code_pda;
+
+use super::OreAccount;
+
+/// ReferralCode maps a short human-readable code to a Garage owner.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ReferralCode {
+    /// The normalized code (uppercase ASCII alphanumerics, zero-padded).
+    pub code: [u8; REFERRAL_CODE_MAX_LEN],
+
+    /// The Garage owner credited for deposits using this code.
+    pub authority: Pubkey,
+
+    /// Timestamp the code was claimed.
+    pub created_at: i64,
+}
+
+impl ReferralCode {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        referral_code_pda(self.code)
+    }
+
+    /// Normalizes a code string: trims whitespace and uppercases ASCII letters.
+    /// Returns None if the code is too short, too long, or contains non-alphanumeric characters.
+    pub fn normalize(code: &str) -> Option<[u8; REFERRAL_CODE_MAX_LEN]> {
+        let code = code.trim();
+        if code.len() < REFERRAL_CODE_MIN_LEN || code.len() > REFERRAL_CODE_MAX_LEN {
+            return None;
+        }
+        let mut normalized = [0u8; REFERRAL_CODE_MAX_LEN];
+        for (i, c) in code.bytes().enumerate() {
+            if !c.is_ascii_alphanumeric() {
+                return None;
+            }
+            normalized[i] = c.to_ascii_uppercase();
+        }
+        Some(normalized)
+    }
+
+    /// Returns true if the bytes are already in normalized form.
+    pub fn is_normalized(code: &[u8; REFERRAL_CODE_MAX_LEN]) -> bool {
+        let len = code.iter().position(|c| *c == 0).unwrap_or(REFERRAL_CODE_MAX_LEN);
+        len >= REFERRAL_CODE_MIN_LEN
+            && code[..len]
+                .iter()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && code[len..].iter().all(|c| *c == 0)
+    }
+
+    /// Returns the code as a string slice, without padding.
+    pub fn code_str(&self) -> &str {
+        let len = self
+            .code
+            .iter()
+            .position(|c| *c == 0)
+            .unwrap_or(REFERRAL_CODE_MAX_LEN);
+        core::str::from_utf8(&self.code[..len]).unwrap_or("")
+    }
+}
+
+account!(OreAccount, ReferralCode);
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::referral_ledger_pda;
+
+use super::OreAccount;
+
+/// ReferralLedger records the deposit volume and referral earnings a referee generated for
+/// their referrer.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct ReferralLedger {
+    /// The referee (Garage owner) whose deposits are tracked.
+    pub referee: Pubkey,
+
+    /// The referrer credited for the referee's deposits.
+    pub referrer: Pubkey,
+
+    /// Total FUEL deposited by the referee via FuelUp.
+    pub total_volume: u64,
+
+    /// Total referral fees credited up the referrer chain from this referee.
+    pub total_earnings: u64,
+
+    /// Total referral fees redirected to the pool because a referrer's Garage was
+    /// closed or exhausted.
+    pub total_redirected: u64,
+
+    /// Number of FuelUp deposits recorded.
+    pub deposit_count: u64,
+
+    /// Timestamp the ledger was created.
+    pub created_at: i64,
+
+    /// Timestamp of the last recorded deposit.
+    pub last_deposit_at: i64,
+}
+
+impl ReferralLedger {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        referral_ledger_pda(self.referee)
+    }
+
+    /// Records a deposit. Totals restart if the referee's referrer has changed.
+    pub fn record(
+        &mut self,
+        referrer: Pubkey,
+        volume: u64,
+        earnings: u64,
+        redirected: u64,
+        now: i64,
+    ) {
+        if self.referrer != referrer {
+            self.referrer = referrer;
+            self.total_volume = 0;
+            self.total_earnings = 0;
+            self.total_redirected = 0;
+            self.deposit_count = 0;
+        }
+        self.total_volume = self.total_volume.saturating_add(volume);
+        self.total_earnings = self.total_earnings.saturating_add(earnings);
+        self.total_redirected = self.total_redirected.saturating_add(redirected);
+        self.deposit_count = self.deposit_count.saturating_add(1);
+        self.last_deposit_at = now;
+    }
+}
+
+account!(OreAccount, ReferralLedger);
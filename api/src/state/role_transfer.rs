@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::role_transfer_pda;
+
+use super::OreAccount;
+
+/// RoleTransfer is a pending proposal to hand a Config role to a new address.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct RoleTransfer {
+    /// The role being transferred (see Role).
+    pub role: u64,
+
+    /// The address that opened the proposal and paid its rent, refunded on close.
+    pub authority: Pubkey,
+
+    /// The proposed new holder, who must accept the transfer.
+    pub recipient: Pubkey,
+
+    /// Timestamp the transfer was proposed.
+    pub created_at: i64,
+}
+
+impl RoleTransfer {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        role_transfer_pda(self.role as u8)
+    }
+
+    /// Whether the Config timelock delay has elapsed since the proposal at `now`.
+    pub fn is_ready(&self, now: i64, timelock_delay: u64) -> bool {
+        now >= self.created_at.saturating_add(timelock_delay as i64)
+    }
+}
+
+account!(OreAccount, RoleTransfer);
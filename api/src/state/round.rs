@@ -46,11 +46,18 @@ pub struct Round {
 
     /// The total amount of SOL won by miners for the round.
     pub total_winnings: u64,
+
+    /// The intermission after this round, in slots. Snapshotted when the round starts.
+    pub intermission_slots: u64,
+
+    /// Slots before expiry during which anyone may checkpoint a miner for the bot fee.
+    /// Snapshotted when the round starts.
+    pub checkpoint_window_slots: u64,
 }
 
 impl Round {
-    pub fn pda(&self) -> (Pubkey, u8) {
-        round_pda(self.id)
+    pub fn pda(&self, table_id: u8) -> (Pubkey, u8) {
+        round_pda(table_id, self.id)
     }
 
     pub fn rng(&self) -> Option<u64> {
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use super::OreAccount;
+use super::{Garage, OreAccount};
 
 /// Treasury is a singleton account which is the mint authority for the FUEL token and the authority of
 /// the program's global token account.
@@ -11,8 +11,8 @@ pub struct Treasury {
     // The amount of SOL collected for buy-bury operations.
     pub balance: u64,
 
-    /// Buffer a (placeholder)
-    pub buffer_a: u64,
+    /// Cumulative FUEL minted by Collect as a backstop for the Garage vault.
+    pub garage_backstop_minted: u64,
 
     /// The amount of FUEL in the motherlode rewards pool.
     pub motherlode: u64,
@@ -23,8 +23,10 @@ pub struct Treasury {
     /// The cumulative FUEL distributed to stakers, divided by the total stake at the time of distribution.
     pub stake_rewards_factor: Numeric,
 
-    /// Buffer b (placeholder)
-    pub buffer_b: u64,
+    /// Total outstanding obligation across tracked Garages (sum of max_payout - total_claimed).
+    /// Used as the denominator for whale tax calculation. Garages that predate this field are
+    /// counted from their next action (see track_garage).
+    pub total_garage_outstanding: u64,
 
     /// The current total amount of refined FUEL mining rewards.
     pub total_refined: u64,
@@ -39,11 +41,41 @@ pub struct Treasury {
     // Garage System Fields
     // ============================================================================
 
-    /// FUEL pool for Garage withdrawals (funded by Fuel Up fees and protocol).
+    /// FUEL held in the Garage vault for withdrawals (funded by FuelUp and Boost pool fees).
     pub garage_pool: u64,
 
-    /// Total FUEL deposited across all Garages (for whale tax calculation).
+    /// Total FUEL deposited across all Garages.
     pub total_garage_tvl: u64,
 }
 
+impl Treasury {
+    /// Adds a Garage that predates total_garage_outstanding at its current obligation.
+    /// A no-op for Garages that are already tracked.
+    pub fn track_garage(&mut self, garage: &mut Garage) -> Result<(), ProgramError> {
+        if garage.outstanding_tracked == 0 {
+            self.total_garage_outstanding = self
+                .total_garage_outstanding
+                .checked_add(garage.outstanding())
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            garage.outstanding_tracked = 1;
+        }
+        Ok(())
+    }
+
+    /// Replaces a tracked Garage's previous outstanding obligation with its new one in
+    /// total_garage_outstanding.
+    pub fn update_garage_outstanding(
+        &mut self,
+        before: u64,
+        after: u64,
+    ) -> Result<(), ProgramError> {
+        self.total_garage_outstanding = self
+            .total_garage_outstanding
+            .checked_sub(before)
+            .and_then(|v| v.checked_add(after))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
 account!(OreAccount, Treasury);
@@ -82,11 +82,47 @@ async fn main() {
         "deploy_all" => {
             deploy_all(&rpc, &payer).await.unwrap();
         }
+        "deploy_multi" => {
+            deploy_multi(&rpc, &payer).await.unwrap();
+        }
         "round" => {
             log_round(&rpc).await.unwrap();
         }
-        "set_admin" => {
-            set_admin(&rpc, &payer).await.unwrap();
+        "set_fee_collector" => {
+            set_fee_collector(&rpc, &payer).await.unwrap();
+        }
+        "set_pause" => {
+            set_pause(&rpc, &payer).await.unwrap();
+        }
+        "set_table" => {
+            set_table(&rpc, &payer).await.unwrap();
+        }
+        "migrate_board" => {
+            migrate_board(&rpc, &payer).await.unwrap();
+        }
+        "migrate_config" => {
+            migrate_config(&rpc, &payer).await.unwrap();
+        }
+        "init_garage_vault" => {
+            init_garage_vault(&rpc, &payer).await.unwrap();
+        }
+        "propose_role_transfer" => {
+            propose_role_transfer(&rpc, &payer).await.unwrap();
+        }
+        "accept_role_transfer" => {
+            accept_role_transfer(&rpc, &payer).await.unwrap();
+        }
+        "propose_change" => {
+            propose_change(&rpc, &payer).await.unwrap();
+        }
+        "execute_change" => {
+            execute_change(&rpc, &payer).await.unwrap();
+        }
+        "cancel_change" => {
+            cancel_change(&rpc, &payer).await.unwrap();
+        }
+        "pending_changes" => {
+            log_pending_changes(&rpc).await.unwrap();
         }
         "ata" => {
             ata(&rpc, &payer).await.unwrap();
@@ -121,6 +157,57 @@ async fn main() {
         "initialize" => {
             initialize(&rpc, &payer).await.unwrap();
         }
+        "garage" => {
+            log_garage(&rpc, &payer).await.unwrap();
+        }
+        "fuel_up" => {
+            fuel_up(&rpc, &payer).await.unwrap();
+        }
+        "fuel_up_with_code" => {
+            fuel_up_with_code(&rpc, &payer).await.unwrap();
+        }
+        "boost" => {
+            boost(&rpc, &payer).await.unwrap();
+        }
+        "collect" => {
+            collect(&rpc, &payer).await.unwrap();
+        }
+        "harvest" => {
+            harvest(&rpc, &payer).await.unwrap();
+        }
+        "automate_garage" => {
+            automate_garage(&rpc, &payer).await.unwrap();
+        }
+        "garage_automation" => {
+            log_garage_automation(&rpc, &payer).await.unwrap();
+        }
+        "propose_garage_transfer" => {
+            propose_garage_transfer(&rpc, &payer).await.unwrap();
+        }
+        "accept_garage_transfer" => {
+            accept_garage_transfer(&rpc, &payer).await.unwrap();
+        }
+        "claim_referral_code" => {
+            claim_referral_code(&rpc, &payer).await.unwrap();
+        }
+        "release_referral_code" => {
+            release_referral_code(&rpc, &payer).await.unwrap();
+        }
+        "transfer_referral_code" => {
+            transfer_referral_code(&rpc, &payer).await.unwrap();
+        }
+        "referral_code" => {
+            log_referral_code(&rpc).await.unwrap();
+        }
+        "referral_ledger" => {
+            log_referral_ledger(&rpc, &payer).await.unwrap();
+        }
+        "stash" => {
+            stash(&rpc, &payer).await.unwrap();
+        }
+        "claim_wallet" => {
+            claim_wallet(&rpc, &payer).await.unwrap();
+        }
         _ => panic!("Invalid command"),
     };
 }
@@ -134,13 +221,17 @@ async fn initialize(
 
     let treasury_address = speedway_api::state::treasury_pda().0;
     let config_address = speedway_api::state::config_pda().0;
-    let board_address = speedway_api::state::board_pda().0;
-    let round_address = speedway_api::state::round_pda(0).0;
+    let board_address = speedway_api::state::board_pda(DEFAULT_TABLE_ID).0;
+    let round_address = speedway_api::state::round_pda(DEFAULT_TABLE_ID, 0).0;
+    let house_garage_address = speedway_api::state::garage_pda(HOUSE_ADDRESS).0;
+    let garage_vault_address = speedway_api::state::garage_vault_pda().0;
 
     println!("Treasury PDA: {}", treasury_address);
     println!("Config PDA: {}", config_address);
     println!("Board PDA: {}", board_address);
     println!("Round 0 PDA: {}", round_address);
+    println!("House Garage PDA: {}", house_garage_address);
+    println!("Garage Vault PDA: {}", garage_vault_address);
 
     let ix = speedway_api::sdk::initialize(payer.pubkey());
     let sig = submit_transaction(rpc, payer, &[ix]).await?;
@@ -229,10 +320,29 @@ async fn new_var(
     let commit = keccak::Hash::from_str(&commit).expect("Invalid COMMIT");
     let samples = std::env::var("SAMPLES").expect("Missing SAMPLES env var");
     let samples = u64::from_str(&samples).expect("Invalid SAMPLES");
-    let board_address = board_pda().0;
-    let var_address = entropy_api::state::var_pda(board_address, 0).0;
+    let table_id = get_table_env();
+    let id = std::env::var("ID").map_or(0, |id| u64::from_str(&id).expect("Invalid ID"));
+    let var_address = entropy_api::state::var_pda(board_pda(table_id).0, id).0;
     println!("Var address: {}", var_address);
-    let ix = speedway_api::sdk::new_var(payer.pubkey(), provider, 0, commit.to_bytes(), samples);
+
+    // Replacing a pinned var consumes a ready Var change (CHANGE is its id).
+    let var_change = match std::env::var("CHANGE") {
+        Ok(change_id) => {
+            let change_id = u64::from_str(&change_id).expect("Invalid CHANGE");
+            let change = get_pending_change(rpc, change_id).await?;
+            Some((change_id, change.proposer))
+        }
+        Err(_) => None,
+    };
+    let ix = speedway_api::sdk::new_var(
+        payer.pubkey(),
+        table_id,
+        provider,
+        id,
+        commit.to_bytes(),
+        samples,
+        var_change,
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
@@ -240,8 +350,12 @@ async fn new_var(
 async fn participating_miners(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let round_id = std::env::var("ID").expect("Missing ID env var");
     let round_id = u64::from_str(&round_id).expect("Invalid ID");
+    let table_id = get_table_env();
     let miners = get_miners_participating(rpc, round_id).await?;
-    for (i, (_address, miner)) in miners.iter().enumerate() {
+    let miners = miners
+        .iter()
+        .filter(|(address, miner)| *address == miner_pda(miner.authority, table_id).0);
+    for (i, (_address, miner)) in miners.enumerate() {
         println!("{}: {}", i, miner.authority);
     }
     Ok(())
@@ -316,10 +430,11 @@ async fn ata(
 async fn keys() -> Result<(), anyhow::Error> {
     let treasury_address = speedway_api::state::treasury_pda().0;
     let config_address = speedway_api::state::config_pda().0;
-    let board_address = speedway_api::state::board_pda().0;
+    let table_id = get_table_env();
+    let board_address = speedway_api::state::board_pda(table_id).0;
     let address = pubkey!("pqspJ298ryBjazPAr95J9sULCVpZe3HbZTWkbC1zrkS");
-    let miner_address = speedway_api::state::miner_pda(address).0;
-    let round = round_pda(31460).0;
+    let miner_address = speedway_api::state::miner_pda(address, table_id).0;
+    let round = round_pda(table_id, 31460).0;
     println!("Round: {}", round);
     println!("Treasury: {}", treasury_address);
     println!("Config: {}", config_address);
@@ -332,8 +447,9 @@ async fn claim(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let ix_sol = speedway_api::sdk::claim_sol(payer.pubkey());
-    let ix_ore = speedway_api::sdk::claim_ore(payer.pubkey());
+    let table_id = get_table_env();
+    let ix_sol = speedway_api::sdk::claim_sol(payer.pubkey(), table_id);
+    let ix_ore = speedway_api::sdk::claim_ore(payer.pubkey(), table_id);
     submit_transaction(rpc, payer, &[ix_sol, ix_ore]).await?;
     Ok(())
 }
@@ -432,19 +548,19 @@ pub async fn get_address_lookup_table_accounts(
     Ok(accounts)
 }
 
-pub const ORE_VAR_ADDRESS: Pubkey = pubkey!("BWCaDY96Xe4WkFq1M7UiCCRcChsJ3p51L5KrGzhxgm2E");
-
 async fn reset(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let board = get_board(rpc).await?;
-    let var = get_var(rpc, ORE_VAR_ADDRESS).await?;
+    let table_id = get_table_env();
+    let board = get_board(rpc, table_id).await?;
+    let var_address = board.var;
+    let var = get_var(rpc, var_address).await?;
 
     println!("Var: {:?}", var);
 
     let client = reqwest::Client::new();
-    let url = format!("https://entropy-api.onrender.com/var/{ORE_VAR_ADDRESS}/seed");
+    let url = format!("https://entropy-api.onrender.com/var/{var_address}/seed");
     let response = client
         .get(url)
         .send()
@@ -454,11 +570,13 @@ async fn reset(
     println!("Entropy seed: {:?}", response);
 
     let config = get_config(rpc).await?;
-    let sample_ix = entropy_api::sdk::sample(payer.pubkey(), ORE_VAR_ADDRESS);
-    let reveal_ix = entropy_api::sdk::reveal(payer.pubkey(), ORE_VAR_ADDRESS, response.seed);
+    let sample_ix = entropy_api::sdk::sample(payer.pubkey(), var_address);
+    let reveal_ix = entropy_api::sdk::reveal(payer.pubkey(), var_address, response.seed);
     let reset_ix = speedway_api::sdk::reset(
         payer.pubkey(),
-        ADMIN_FEE_COLLECTOR,
+        config.fee_collector,
+        table_id,
+        var_address,
         board.round_id,
         Pubkey::default(),
     );
@@ -476,12 +594,15 @@ async fn deploy(
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
     let square_id = std::env::var("SQUARE").expect("Missing SQUARE env var");
     let square_id = u64::from_str(&square_id).expect("Invalid SQUARE");
-    let board = get_board(rpc).await?;
+    let table_id = get_table_env();
+    let board = get_board(rpc, table_id).await?;
     let mut squares = [false; 25];
     squares[square_id as usize] = true;
     let ix = speedway_api::sdk::deploy(
         payer.pubkey(),
         payer.pubkey(),
+        table_id,
+        board.var,
         amount,
         board.round_id,
         squares,
@@ -496,36 +617,616 @@ async fn deploy_all(
 ) -> Result<(), anyhow::Error> {
     let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
-    let board = get_board(rpc).await?;
+    let table_id = get_table_env();
+    let board = get_board(rpc, table_id).await?;
     let squares = [true; 25];
     let ix = speedway_api::sdk::deploy(
         payer.pubkey(),
         payer.pubkey(),
-        board.round_id,
+        table_id,
+        board.var,
         amount,
+        board.round_id,
         squares,
     );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn set_admin(
+async fn deploy_multi(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    // SQUARES is a comma-separated list of square:lamports pairs, e.g. "0:500000000,12:100000000".
+    let squares = std::env::var("SQUARES").expect("Missing SQUARES env var");
+    let squares = squares
+        .split(',')
+        .map(|pair| {
+            let (square, amount) = pair.split_once(':').expect("Invalid SQUARES entry");
+            (
+                u8::from_str(square.trim()).expect("Invalid square"),
+                u64::from_str(amount.trim()).expect("Invalid amount"),
+            )
+        })
+        .collect::<Vec<_>>();
+    let table_id = get_table_env();
+    let board = get_board(rpc, table_id).await?;
+    let ix = speedway_api::sdk::deploy_multi(
+        payer.pubkey(),
+        table_id,
+        board.var,
+        board.round_id,
+        &squares,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn fuel_up(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let referrer = std::env::var("REFERRER").unwrap_or(HOUSE_ADDRESS.to_string());
+    let referrer = Pubkey::from_str(&referrer).expect("Invalid REFERRER");
+    let referrer = get_stored_referrer(rpc, payer.pubkey()).await.unwrap_or(referrer);
+    let upline = get_upline(rpc, referrer).await;
+    let config = get_config(rpc).await?;
+    let ix = speedway_api::sdk::fuel_up(
+        payer.pubkey(),
+        payer.pubkey(),
+        config.fee_collector,
+        referrer,
+        &upline,
+        amount,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn fuel_up_with_code(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let code = get_referral_code_env();
+    let account = rpc.get_account(&referral_code_pda(code).0).await?;
+    let referral_code = ReferralCode::try_from_bytes(&account.data)?;
+    let referrer = get_stored_referrer(rpc, payer.pubkey())
+        .await
+        .unwrap_or(referral_code.authority);
+    let upline = get_upline(rpc, referrer).await;
+    let config = get_config(rpc).await?;
+    let ix = speedway_api::sdk::fuel_up_with_code(
+        payer.pubkey(),
+        payer.pubkey(),
+        config.fee_collector,
+        code,
+        referrer,
+        &upline,
+        amount,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// Returns the referrer stored in the authority's Garage, if it exists.
+async fn get_stored_referrer(rpc: &RpcClient, authority: Pubkey) -> Option<Pubkey> {
+    let account = rpc.get_account(&garage_pda(authority).0).await.ok()?;
+    let garage = Garage::try_from_bytes(&account.data).ok()?;
+    Some(garage.referrer)
+}
+
+/// Returns the recipient a closed Garage was transferred to, if it was.
+async fn get_forward_recipient(rpc: &RpcClient, authority: Pubkey) -> Option<Pubkey> {
+    let account = rpc.get_account(&garage_forward_pda(authority).0).await.ok()?;
+    let forward = GarageForward::try_from_bytes(&account.data).ok()?;
+    Some(forward.recipient)
+}
+
+/// Returns the remaining accounts of the referral chain above `referrer` (see sdk::fuel_up).
+/// Closed Garages are followed through their forward. The walk stops at the house, after the
+/// last level, or at a closed Garage that was not transferred.
+async fn get_upline(rpc: &RpcClient, referrer: Pubkey) -> Vec<UplineAccount> {
+    let mut upline = vec![];
+    let mut current = referrer;
+    for level in 0..REFERRAL_LEVELS {
+        if level > 0 {
+            upline.push(UplineAccount::Garage(current));
+        }
+
+        // Follow forwards from transferred Garages.
+        let next = loop {
+            if let Some(next) = get_stored_referrer(rpc, current).await {
+                break Some(next);
+            }
+            upline.push(UplineAccount::Forward(current));
+            let Some(recipient) = get_forward_recipient(rpc, current).await else {
+                break None;
+            };
+            current = recipient;
+            upline.push(UplineAccount::Garage(current));
+        };
+        let Some(next) = next else {
+            break;
+        };
+        if current == HOUSE_ADDRESS || next == Pubkey::default() {
+            break;
+        }
+        current = next;
+    }
+    upline
+}
+
+async fn boost(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").unwrap_or("0".to_string());
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let config = get_config(rpc).await?;
+    let ix = speedway_api::sdk::boost(payer.pubkey(), authority, config.fee_collector, amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn collect(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").unwrap_or("0".to_string());
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let config = get_config(rpc).await?;
+    let ix = speedway_api::sdk::collect(payer.pubkey(), authority, config.fee_collector, amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn automate_garage(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let executor = std::env::var("EXECUTOR").unwrap_or(EXECUTOR_ADDRESS.to_string());
+    let executor = Pubkey::from_str(&executor).expect("Invalid EXECUTOR");
+    let deposit = std::env::var("DEPOSIT").unwrap_or("0".to_string());
+    let deposit = u64::from_str(&deposit).expect("Invalid DEPOSIT");
+    let fee = std::env::var("FEE").expect("Missing FEE env var");
+    let fee = u64::from_str(&fee).expect("Invalid FEE");
+    let interval = std::env::var("INTERVAL").unwrap_or(ONE_DAY.to_string());
+    let interval = u64::from_str(&interval).expect("Invalid INTERVAL");
+    let boost_count = std::env::var("BOOST_COUNT").unwrap_or("0".to_string());
+    let boost_count = u64::from_str(&boost_count).expect("Invalid BOOST_COUNT");
+    let strategy = std::env::var("STRATEGY").unwrap_or("0".to_string());
+    let strategy = u8::from_str(&strategy).expect("Invalid STRATEGY");
+    let ix = speedway_api::sdk::automate_garage(
+        payer.pubkey(),
+        executor,
+        deposit,
+        fee,
+        interval,
+        boost_count,
+        strategy,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn propose_garage_transfer(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let recipient = std::env::var("RECIPIENT").expect("Missing RECIPIENT env var");
+    let recipient = Pubkey::from_str(&recipient).expect("Invalid RECIPIENT");
+    let ix = speedway_api::sdk::propose_garage_transfer(payer.pubkey(), recipient);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn accept_garage_transfer(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").expect("Missing AUTHORITY env var");
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    // A recipient who referred the Garage hands the referral to the house.
+    let referrer_upline = if get_stored_referrer(rpc, authority).await == Some(payer.pubkey()) {
+        get_upline(rpc, payer.pubkey()).await
+    } else {
+        vec![]
+    };
+    let ix = speedway_api::sdk::accept_garage_transfer(payer.pubkey(), authority, &referrer_upline);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+fn get_referral_code_env() -> [u8; REFERRAL_CODE_MAX_LEN] {
+    let code = std::env::var("CODE").expect("Missing CODE env var");
+    ReferralCode::normalize(&code).expect("Invalid CODE")
+}
+
+async fn claim_referral_code(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let code = get_referral_code_env();
+    let ix = speedway_api::sdk::claim_referral_code(payer.pubkey(), code);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn release_referral_code(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let code = get_referral_code_env();
+    let ix = speedway_api::sdk::release_referral_code(payer.pubkey(), code);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn transfer_referral_code(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let code = get_referral_code_env();
+    let new_authority = std::env::var("NEW_AUTHORITY").expect("Missing NEW_AUTHORITY env var");
+    let new_authority = Pubkey::from_str(&new_authority).expect("Invalid NEW_AUTHORITY");
+    let ix = speedway_api::sdk::transfer_referral_code(payer.pubkey(), code, new_authority);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn harvest(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let boost_bps = std::env::var("BOOST_BPS").expect("Missing BOOST_BPS env var");
+    let boost_bps = u64::from_str(&boost_bps).expect("Invalid BOOST_BPS");
+    let amount = std::env::var("AMOUNT").unwrap_or("0".to_string());
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let config = get_config(rpc).await?;
+    let ix = speedway_api::sdk::harvest(payer.pubkey(), config.fee_collector, boost_bps, amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn stash(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = speedway_api::sdk::stash(payer.pubkey(), get_table_env());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn claim_wallet(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let ix =
+        speedway_api::sdk::claim_wallet(payer.pubkey(), config.fee_collector, get_table_env());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_fee_collector(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let fee_collector = std::env::var("FEE_COLLECTOR").expect("Missing FEE_COLLECTOR env var");
+    let fee_collector = Pubkey::from_str(&fee_collector).expect("Invalid FEE_COLLECTOR");
+    let ix = speedway_api::sdk::set_fee_collector(payer.pubkey(), fee_collector);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Proposed fee collector {}; it must run accept_role_transfer", fee_collector);
+    Ok(())
+}
+
+async fn propose_role_transfer(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let role = get_role_env();
+    let recipient = std::env::var("RECIPIENT").expect("Missing RECIPIENT env var");
+    let recipient = Pubkey::from_str(&recipient).expect("Invalid RECIPIENT");
+    let ix = speedway_api::sdk::propose_role_transfer(payer.pubkey(), role, recipient);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn accept_role_transfer(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let role = get_role_env();
+    let address = speedway_api::state::role_transfer_pda(role as u8).0;
+    let account = rpc.get_account(&address).await?;
+    let transfer = RoleTransfer::try_from_bytes(&account.data)?;
+    if transfer.recipient != payer.pubkey() {
+        return Err(anyhow::anyhow!(
+            "{:?} transfer is proposed to {}, not {}",
+            role,
+            transfer.recipient,
+            payer.pubkey()
+        ));
+    }
+    let ix = speedway_api::sdk::accept_role_transfer(payer.pubkey(), role, transfer.authority);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// Reads the ROLE env var (admin, treasury_operator, pauser or fee_collector).
+fn get_table_env() -> u8 {
+    std::env::var("TABLE")
+        .map(|table| u8::from_str(&table).expect("Invalid TABLE"))
+        .unwrap_or(DEFAULT_TABLE_ID)
+}
+
+fn get_role_env() -> Role {
+    let role = std::env::var("ROLE").expect("Missing ROLE env var");
+    match role.as_str() {
+        "admin" => Role::Admin,
+        "treasury_operator" => Role::TreasuryOperator,
+        "pauser" => Role::Pauser,
+        "fee_collector" => Role::FeeCollector,
+        _ => panic!("Invalid ROLE: {}", role),
+    }
+}
+
+async fn migrate_board(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let table_id = get_table_env();
+    let ix = speedway_api::sdk::migrate_board(payer.pubkey(), table_id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn migrate_config(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = speedway_api::sdk::migrate_config(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn init_garage_vault(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = speedway_api::sdk::init_garage_vault(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Garage vault: {}", garage_vault_pda().0);
+    Ok(())
+}
+
+async fn set_table(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let table_id = get_table_env();
+    let (min_deploy, round_slots, intermission_slots) = get_table_params_env();
+    let ix = speedway_api::sdk::set_table(
+        payer.pubkey(),
+        table_id,
+        min_deploy,
+        round_slots,
+        intermission_slots,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!("Table {}: {}", table_id, board_pda(table_id).0);
+    Ok(())
+}
+
+/// Reads the MIN_DEPLOY, ROUND_SLOTS and INTERMISSION_SLOTS env vars (0 if unset).
+fn get_table_params_env() -> (u64, u64, u64) {
+    let min_deploy = std::env::var("MIN_DEPLOY").unwrap_or("0".to_string());
+    let min_deploy = u64::from_str(&min_deploy).expect("Invalid MIN_DEPLOY");
+    let round_slots = std::env::var("ROUND_SLOTS").unwrap_or("0".to_string());
+    let round_slots = u64::from_str(&round_slots).expect("Invalid ROUND_SLOTS");
+    let intermission_slots = std::env::var("INTERMISSION_SLOTS").unwrap_or("0".to_string());
+    let intermission_slots =
+        u64::from_str(&intermission_slots).expect("Invalid INTERMISSION_SLOTS");
+    (min_deploy, round_slots, intermission_slots)
+}
+
+const PAUSE_FLAGS: [(&str, u64); 5] = [
+    ("deploy", PAUSE_DEPLOY),
+    ("reset", PAUSE_RESET),
+    ("garage_deposit", PAUSE_GARAGE_DEPOSIT),
+    ("garage_withdraw", PAUSE_GARAGE_WITHDRAW),
+    ("claim", PAUSE_CLAIM),
+];
+
+async fn set_pause(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    // PAUSE is a comma-separated list of flag names, "all" or "none".
+    let pause = std::env::var("PAUSE").expect("Missing PAUSE env var");
+    let mask = match pause.as_str() {
+        "all" => PAUSE_ALL,
+        "none" => 0,
+        _ => pause.split(',').fold(0, |mask, name| {
+            let (_, flag) = PAUSE_FLAGS
+                .iter()
+                .find(|(n, _)| *n == name.trim())
+                .unwrap_or_else(|| panic!("Invalid PAUSE flag: {}", name));
+            mask | flag
+        }),
+    };
+    let ix = speedway_api::sdk::set_pause(payer.pubkey(), mask);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+fn pause_names(mask: u64) -> Vec<&'static str> {
+    PAUSE_FLAGS
+        .iter()
+        .filter(|(_, flag)| mask & flag != 0)
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+async fn propose_change(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let kind = std::env::var("KIND").expect("Missing KIND env var");
+    let mut value = 0;
+    let mut params = ProtocolParams::default();
+    let kind = match kind.as_str() {
+        "params" => {
+            params = get_params_env(rpc).await?;
+            ChangeKind::Params
+        }
+        "backstop_cap" | "timelock_delay" => {
+            let v = std::env::var("VALUE").expect("Missing VALUE env var");
+            value = u64::from_str(&v).expect("Invalid VALUE");
+            if kind == "backstop_cap" {
+                ChangeKind::BackstopCap
+            } else {
+                ChangeKind::TimelockDelay
+            }
+        }
+        "var" | "table" => {
+            value = get_table_env() as u64;
+            if kind == "var" {
+                ChangeKind::Var
+            } else {
+                ChangeKind::Table
+            }
+        }
+        _ => return Err(anyhow::anyhow!("Invalid KIND: {}", kind)),
+    };
+
+    // Default to the earliest allowed ETA, with a small margin for clock drift.
+    let eta = match std::env::var("ETA") {
+        Ok(eta) => i64::from_str(&eta).expect("Invalid ETA"),
+        Err(_) => {
+            let clock = get_clock(rpc).await?;
+            clock.unix_timestamp + config.timelock_delay as i64 + ONE_MINUTE
+        }
+    };
+    println!("Proposing change {} ({:?}), eta {}", config.change_count, kind, eta);
+    let ix = if kind == ChangeKind::Table {
+        let (min_deploy, round_slots, intermission_slots) = get_table_params_env();
+        speedway_api::sdk::propose_table_change(
+            payer.pubkey(),
+            config.change_count,
+            value as u8,
+            min_deploy,
+            round_slots,
+            intermission_slots,
+            eta,
+        )
+    } else {
+        speedway_api::sdk::propose_change(
+            payer.pubkey(),
+            config.change_count,
+            kind,
+            value,
+            params,
+            eta,
+        )
+    };
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn execute_change(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let pending_change = get_pending_change(rpc, id).await?;
+    let table_id = if pending_change.kind == ChangeKind::Table as u64 {
+        pending_change.value as u8
+    } else {
+        DEFAULT_TABLE_ID
+    };
+    let ix = speedway_api::sdk::execute_change(
+        payer.pubkey(),
+        id,
+        pending_change.proposer,
+        table_id,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn cancel_change(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let ix = speedway_api::sdk::set_admin(payer.pubkey(), payer.pubkey());
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let pending_change = get_pending_change(rpc, id).await?;
+    let ix = speedway_api::sdk::cancel_change(payer.pubkey(), id, pending_change.proposer);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
+/// Reads protocol param overrides from env vars on top of the current on-chain params.
+async fn get_params_env(rpc: &RpcClient) -> Result<ProtocolParams, anyhow::Error> {
+    // Start from the current on-chain params, falling back to defaults for unmigrated configs.
+    let mut params = get_config(rpc)
+        .await
+        .map(|c| c.params)
+        .unwrap_or_default();
+    let env_u64 = |name: &str, value: &mut u64| {
+        if let Ok(v) = std::env::var(name) {
+            *value = u64::from_str(&v).unwrap_or_else(|_| panic!("Invalid {}", name));
+        }
+    };
+    fn env_tiers<const N: usize>(name: &str, value: &mut [u64; N]) {
+        if let Ok(v) = std::env::var(name) {
+            let tiers: Vec<u64> = v
+                .split(',')
+                .map(|t| u64::from_str(t.trim()).unwrap_or_else(|_| panic!("Invalid {}", name)))
+                .collect();
+            *value = tiers
+                .try_into()
+                .unwrap_or_else(|_| panic!("{} needs {} values", name, N));
+        }
+    }
+    env_u64("DAILY_RATE_BPS", &mut params.daily_rate_bps);
+    env_u64("MAX_PAYOUT_MULT", &mut params.max_payout_mult);
+    env_u64("COLLECT_TAX_BPS", &mut params.collect_tax_bps);
+    env_tiers("WHALE_TAX_THRESHOLDS", &mut params.whale_tax_threshold_bps);
+    env_tiers("WHALE_TAX_RATES", &mut params.whale_tax_rate_bps);
+    env_u64("SPRINT_TEAM_FEE_BPS", &mut params.sprint_team_fee_bps);
+    env_u64("SPRINT_BUYBACK_FEE_BPS", &mut params.sprint_buyback_fee_bps);
+    env_u64("CHECKPOINT_FEE", &mut params.checkpoint_fee);
+    env_u64("ROUND_SLOTS", &mut params.round_slots);
+    env_u64("INTERMISSION_SLOTS", &mut params.intermission_slots);
+    env_u64("CLAIM_EXPIRY_SLOTS", &mut params.claim_expiry_slots);
+    env_tiers("REF_LEVEL_BPS", &mut params.ref_level_bps);
+    if !params.is_valid() {
+        return Err(anyhow::anyhow!("Params out of bounds: {:?}", params));
+    }
+    Ok(params)
+}
+
 async fn checkpoint(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
-    let miner = get_miner(rpc, authority).await?;
-    let ix = speedway_api::sdk::checkpoint(payer.pubkey(), authority, miner.round_id);
+    let table_id = get_table_env();
+    let miner = get_miner(rpc, authority, table_id).await?;
+    let ix = speedway_api::sdk::checkpoint(payer.pubkey(), authority, table_id, miner.round_id);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
@@ -535,14 +1236,21 @@ async fn checkpoint_all(
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     let clock = get_clock(rpc).await?;
+    let config = get_config(rpc).await?;
+    let checkpoint_window = config.params.checkpoint_window_slots();
+    let table_id = get_table_env();
     let miners = get_miners(rpc).await?;
     let mut expiry_slots = HashMap::new();
     let mut ixs = vec![];
-    for (i, (_address, miner)) in miners.iter().enumerate() {
+    for (i, (address, miner)) in miners.iter().enumerate() {
+        // Skip miners at other tables.
+        if *address != miner_pda(miner.authority, table_id).0 {
+            continue;
+        }
         if miner.checkpoint_id < miner.round_id {
             // Log the expiry slot for the round.
             if !expiry_slots.contains_key(&miner.round_id) {
-                if let Ok(round) = get_round(rpc, miner.round_id).await {
+                if let Ok(round) = get_round(rpc, table_id, miner.round_id).await {
                     expiry_slots.insert(miner.round_id, round.expires_at);
                 }
             }
@@ -553,7 +1261,7 @@ async fn checkpoint_all(
             };
 
             // If we are in fee collection period, checkpoint the miner.
-            if clock.slot >= expires_at - TWELVE_HOURS_SLOTS {
+            if clock.slot >= expires_at.saturating_sub(checkpoint_window) {
                 println!(
                     "[{}/{}] Checkpoint miner: {} ({} s)",
                     i + 1,
@@ -564,6 +1272,7 @@ async fn checkpoint_all(
                 ixs.push(speedway_api::sdk::checkpoint(
                     payer.pubkey(),
                     miner.authority,
+                    table_id,
                     miner.round_id,
                 ));
             }
@@ -585,13 +1294,19 @@ async fn close_all(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
+    let table_id = get_table_env();
     let rounds = get_rounds(rpc).await?;
     let mut ixs = vec![];
     let clock = get_clock(rpc).await?;
-    for (_i, (_address, round)) in rounds.iter().enumerate() {
+    for (_i, (address, round)) in rounds.iter().enumerate() {
+        // Skip rounds at other tables.
+        if *address != round_pda(table_id, round.id).0 {
+            continue;
+        }
         if clock.slot >= round.expires_at {
             ixs.push(speedway_api::sdk::close(
                 payer.pubkey(),
+                table_id,
                 round.id,
                 round.rent_payer,
             ));
@@ -630,6 +1345,120 @@ async fn log_automation(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     println!("  mask: {}", automation.mask);
     println!("  strategy: {}", automation.strategy);
     println!("  reload: {}", automation.reload);
+    println!("  round budget: {} SOL (0 = no cap)", lamports_to_sol(automation.round_budget));
+    println!("  daily budget: {} SOL (0 = no cap)", lamports_to_sol(automation.daily_budget));
+    println!("  round spent: {} SOL", lamports_to_sol(automation.round_spent));
+    println!("  day spent: {} SOL", lamports_to_sol(automation.day_spent));
+    println!("  stop loss: {} SOL (0 = no limit)", lamports_to_sol(automation.stop_loss));
+    println!("  losses: {} SOL", lamports_to_sol(automation.losses()));
+    println!("  stopped: {}", automation.stopped);
+    println!(
+        "  rounds played: {} / {} (0 = no limit)",
+        automation.rounds_played, automation.max_rounds
+    );
+    println!("  expires at slot: {} (0 = never)", automation.expires_at_slot);
+    Ok(())
+}
+
+async fn log_garage_automation(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let address = garage_automation_pda(authority).0;
+    let account = rpc.get_account(&address).await?;
+    let automation = GarageAutomation::try_from_bytes(&account.data)?;
+    let clock = get_clock(rpc).await?;
+    println!("GarageAutomation");
+    println!("  address: {}", address);
+    println!("  authority: {}", automation.authority);
+    println!("  executor: {}", automation.executor);
+    println!("  balance: {} SOL", lamports_to_sol(automation.balance));
+    println!("  fee: {} SOL", lamports_to_sol(automation.fee));
+    println!("  strategy: {}", automation.strategy);
+    println!("  interval: {} sec", automation.interval);
+    println!("  boost_count: {}", automation.boost_count);
+    println!("  boosts_since_collect: {}", automation.boosts_since_collect);
+    println!("  last_executed_at: {}", automation.last_executed_at);
+    println!("  can_boost: {}", automation.can_boost(clock.unix_timestamp));
+    println!("  can_collect: {}", automation.can_collect(clock.unix_timestamp));
+    Ok(())
+}
+
+async fn log_referral_ledger(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let referee = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let referee = Pubkey::from_str(&referee).expect("Invalid AUTHORITY");
+    let address = referral_ledger_pda(referee).0;
+    let account = rpc.get_account(&address).await?;
+    let ledger = ReferralLedger::try_from_bytes(&account.data)?;
+    println!("ReferralLedger");
+    println!("  address: {}", address);
+    println!("  referee: {}", ledger.referee);
+    println!("  referrer: {}", ledger.referrer);
+    println!(
+        "  total_volume: {} FUEL",
+        amount_to_ui_amount(ledger.total_volume, TOKEN_DECIMALS)
+    );
+    println!(
+        "  total_earnings: {} FUEL",
+        amount_to_ui_amount(ledger.total_earnings, TOKEN_DECIMALS)
+    );
+    println!(
+        "  total_redirected: {} FUEL",
+        amount_to_ui_amount(ledger.total_redirected, TOKEN_DECIMALS)
+    );
+    println!("  deposit_count: {}", ledger.deposit_count);
+    println!("  created_at: {}", ledger.created_at);
+    println!("  last_deposit_at: {}", ledger.last_deposit_at);
+    Ok(())
+}
+
+async fn log_referral_code(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let code = get_referral_code_env();
+    let address = referral_code_pda(code).0;
+    let account = rpc.get_account(&address).await?;
+    let referral_code = ReferralCode::try_from_bytes(&account.data)?;
+    println!("ReferralCode");
+    println!("  address: {}", address);
+    println!("  code: {}", referral_code.code_str());
+    println!("  authority: {}", referral_code.authority);
+    println!("  created_at: {}", referral_code.created_at);
+    Ok(())
+}
+
+async fn log_pending_changes(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let clock = get_clock(rpc).await?;
+    let pending_changes = get_program_accounts::<PendingChange>(rpc, speedway_api::ID, vec![]).await?;
+    for (address, change) in pending_changes {
+        println!("PendingChange {}", change.id);
+        println!("  address: {}", address);
+        println!("  kind: {:?}", ChangeKind::try_from(change.kind as u8));
+        println!("  proposer: {}", change.proposer);
+        match ChangeKind::try_from(change.kind as u8) {
+            Ok(ChangeKind::Params) => println!("  params: {:?}", change.params),
+            Ok(ChangeKind::Table) => {
+                println!("  table: {}", change.value);
+                println!("  min_deploy: {}", change.min_deploy);
+                println!("  round_slots: {}", change.round_slots);
+                println!("  intermission_slots: {}", change.intermission_slots);
+            }
+            _ => println!("  value: {}", change.value),
+        }
+        println!("  created_at: {}", change.created_at);
+        println!(
+            "  eta: {} ({})",
+            change.eta,
+            if change.is_ready(clock.unix_timestamp) {
+                "ready".to_string()
+            } else {
+                format!("in {} sec", change.eta - clock.unix_timestamp)
+            }
+        );
+    }
     Ok(())
 }
 
@@ -653,7 +1482,6 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let treasury = get_treasury(rpc).await?;
     println!("Treasury");
     println!("  address: {}", treasury_address);
-    println!("  buffer_a: {}", treasury.buffer_a);
     println!("  balance: {} SOL", lamports_to_sol(treasury.balance));
     println!(
         "  motherlode: {} ORE",
@@ -667,7 +1495,6 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  stake_rewards_factor: {}",
         treasury.stake_rewards_factor.to_i80f48().to_string()
     );
-    println!("  buffer_b: {}", treasury.buffer_b);
     println!(
         "  total_refined: {} ORE",
         amount_to_ui_amount(treasury.total_refined, TOKEN_DECIMALS)
@@ -680,14 +1507,32 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         "  total_unclaimed: {} ORE",
         amount_to_ui_amount(treasury.total_unclaimed, TOKEN_DECIMALS)
     );
+    println!("  garage_vault: {}", garage_vault_pda().0);
+    println!(
+        "  garage_pool: {} FUEL",
+        amount_to_ui_amount(treasury.garage_pool, TOKEN_DECIMALS)
+    );
+    println!(
+        "  garage_backstop_minted: {} FUEL",
+        amount_to_ui_amount(treasury.garage_backstop_minted, TOKEN_DECIMALS)
+    );
+    println!(
+        "  total_garage_tvl: {} FUEL",
+        amount_to_ui_amount(treasury.total_garage_tvl, TOKEN_DECIMALS)
+    );
+    println!(
+        "  total_garage_outstanding: {} FUEL",
+        amount_to_ui_amount(treasury.total_garage_outstanding, TOKEN_DECIMALS)
+    );
     Ok(())
 }
 
 async fn log_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let id = std::env::var("ID").expect("Missing ID env var");
     let id = u64::from_str(&id).expect("Invalid ID");
-    let round_address = round_pda(id).0;
-    let round = get_round(rpc, id).await?;
+    let table_id = get_table_env();
+    let round_address = round_pda(table_id, id).0;
+    let round = get_round(rpc, table_id, id).await?;
     let rng = round.rng();
     println!("Round");
     println!("  Address: {}", round_address);
@@ -735,8 +1580,9 @@ async fn log_miner(
     let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
     let treasury = get_treasury(&rpc).await?;
-    let miner_address = speedway_api::state::miner_pda(authority).0;
-    let mut miner = get_miner(&rpc, authority).await?;
+    let table_id = get_table_env();
+    let miner_address = speedway_api::state::miner_pda(authority, table_id).0;
+    let mut miner = get_miner(&rpc, authority, table_id).await?;
     miner.update_rewards(&treasury);
     println!("Miner");
     println!("  address: {}", miner_address);
@@ -765,6 +1611,90 @@ async fn log_miner(
     Ok(())
 }
 
+async fn log_garage(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let garage_address = garage_pda(authority).0;
+    let garage = get_garage(rpc, authority).await?;
+    let treasury = get_treasury(rpc).await?;
+    let config = get_config(rpc).await?;
+    let clock = get_clock(rpc).await?;
+    let now = clock.unix_timestamp;
+    let available = garage.calculate_available(now, config.params.daily_rate_bps);
+    // Untracked Garages are added to the total on their next action.
+    let total_outstanding = if garage.outstanding_tracked == 0 {
+        treasury.total_garage_outstanding + garage.outstanding()
+    } else {
+        treasury.total_garage_outstanding
+    };
+    let whale_tax_bps = config
+        .params
+        .whale_tax_bps(garage.outstanding(), total_outstanding);
+    let daily_accrual = garage
+        .total_deposited
+        .saturating_mul(config.params.daily_rate_bps)
+        / DENOMINATOR_BPS;
+    println!("Garage");
+    println!("  address: {}", garage_address);
+    println!("  authority: {}", garage.authority);
+    println!("  referrer: {}", garage.referrer);
+    println!(
+        "  total_deposited: {} FUEL",
+        amount_to_ui_amount(garage.total_deposited, TOKEN_DECIMALS)
+    );
+    println!(
+        "  total_claimed: {} FUEL",
+        amount_to_ui_amount(garage.total_claimed, TOKEN_DECIMALS)
+    );
+    println!(
+        "  max_payout: {} FUEL",
+        amount_to_ui_amount(garage.max_payout, TOKEN_DECIMALS)
+    );
+    println!("  last_action_at: {}", garage.last_action_at);
+    println!("  created_at: {}", garage.created_at);
+    println!("  direct_referrals: {}", garage.direct_referrals);
+    println!(
+        "  lifetime_ref_earnings: {} FUEL",
+        amount_to_ui_amount(garage.lifetime_ref_earnings, TOKEN_DECIMALS)
+    );
+    println!("  cycle: {}", garage.cycle);
+    println!(
+        "  lifetime_deposited: {} FUEL",
+        amount_to_ui_amount(garage.lifetime_deposited, TOKEN_DECIMALS)
+    );
+    println!(
+        "  lifetime_claimed: {} FUEL",
+        amount_to_ui_amount(garage.lifetime_claimed, TOKEN_DECIMALS)
+    );
+    for level in 0..REFERRAL_LEVELS {
+        println!(
+            "  level_{}: {} referrals, {} FUEL earned",
+            level + 1,
+            garage.level_referrals[level],
+            amount_to_ui_amount(garage.level_ref_earnings[level], TOKEN_DECIMALS)
+        );
+    }
+    println!("  exhausted: {}", garage.is_exhausted());
+    println!(
+        "  available: {} FUEL",
+        amount_to_ui_amount(available, TOKEN_DECIMALS)
+    );
+    println!(
+        "  whale_tax: {}% ({} BPS of {} FUEL outstanding)",
+        whale_tax_bps as f64 / 100.0,
+        whale_tax_bps,
+        amount_to_ui_amount(total_outstanding, TOKEN_DECIMALS)
+    );
+    println!(
+        "  daily_accrual: {} FUEL",
+        amount_to_ui_amount(daily_accrual, TOKEN_DECIMALS)
+    );
+    Ok(())
+}
+
 async fn log_clock(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let clock = get_clock(&rpc).await?;
     println!("Clock");
@@ -780,11 +1710,47 @@ async fn log_config(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let config = get_config(&rpc).await?;
     println!("Config");
     println!("  admin: {}", config.admin);
+    println!("  treasury_operator: {}", config.treasury_operator);
+    println!("  pauser: {}", config.pauser);
+    println!("  fee_collector: {}", config.fee_collector);
+    println!("  timelock_delay: {} sec", config.timelock_delay);
+    println!("  change_count: {}", config.change_count);
+    println!(
+        "  paused: {:#x} {:?}",
+        config.paused,
+        pause_names(config.paused)
+    );
+    println!(
+        "  garage_backstop_cap: {} FUEL",
+        amount_to_ui_amount(config.garage_backstop_cap, TOKEN_DECIMALS)
+    );
+    println!("  version: {}", config.version);
+    println!("  daily_rate_bps: {}", config.params.daily_rate_bps);
+    println!("  max_payout_mult: {}", config.params.max_payout_mult);
+    println!("  collect_tax_bps: {}", config.params.collect_tax_bps);
+    println!(
+        "  whale_tax_threshold_bps: {:?}",
+        config.params.whale_tax_threshold_bps
+    );
+    println!("  whale_tax_rate_bps: {:?}", config.params.whale_tax_rate_bps);
+    println!("  sprint_team_fee_bps: {}", config.params.sprint_team_fee_bps);
+    println!(
+        "  sprint_buyback_fee_bps: {}",
+        config.params.sprint_buyback_fee_bps
+    );
+    println!(
+        "  checkpoint_fee: {} SOL",
+        lamports_to_sol(config.params.checkpoint_fee)
+    );
+    println!("  round_slots: {}", config.params.round_slots);
+    println!("  intermission_slots: {}", config.params.intermission_slots);
+    println!("  claim_expiry_slots: {}", config.params.claim_expiry_slots);
+    println!("  ref_level_bps: {:?}", config.params.ref_level_bps);
     Ok(())
 }
 
 async fn log_board(rpc: &RpcClient) -> Result<(), anyhow::Error> {
-    let board = get_board(&rpc).await?;
+    let board = get_board(&rpc, get_table_env()).await?;
     let clock = get_clock(&rpc).await?;
     print_board(board, &clock);
     Ok(())
@@ -801,6 +1767,10 @@ fn print_board(board: Board, clock: &Clock) {
         (board.end_slot.saturating_sub(current_slot) as f64) * 0.4
     );
     println!("  Epoch id: {:?}", board.epoch_id);
+    println!("  Table id: {}", board.table_id);
+    println!("  Min deploy: {} SOL", lamports_to_sol(board.min_deploy));
+    println!("  Round slots: {} (0 = config)", board.round_slots);
+    println!("  Intermission slots: {} (0 = config)", board.intermission_slots);
 }
 
 async fn get_automation(rpc: &RpcClient, address: Pubkey) -> Result<Automation, anyhow::Error> {
@@ -819,8 +1789,8 @@ async fn get_automations(rpc: &RpcClient) -> Result<Vec<(Pubkey, Automation)>, a
     Ok(automations)
 }
 
-async fn get_board(rpc: &RpcClient) -> Result<Board, anyhow::Error> {
-    let board_pda = speedway_api::state::board_pda();
+async fn get_board(rpc: &RpcClient, table_id: u8) -> Result<Board, anyhow::Error> {
+    let board_pda = speedway_api::state::board_pda(table_id);
     let account = rpc.get_account(&board_pda.0).await?;
     let board = Board::try_from_bytes(&account.data)?;
     Ok(*board)
@@ -832,8 +1802,8 @@ async fn get_var(rpc: &RpcClient, address: Pubkey) -> Result<Var, anyhow::Error>
     Ok(*var)
 }
 
-async fn get_round(rpc: &RpcClient, id: u64) -> Result<Round, anyhow::Error> {
-    let round_pda = speedway_api::state::round_pda(id);
+async fn get_round(rpc: &RpcClient, table_id: u8, id: u64) -> Result<Round, anyhow::Error> {
+    let round_pda = speedway_api::state::round_pda(table_id, id);
     let account = rpc.get_account(&round_pda.0).await?;
     let round = Round::try_from_bytes(&account.data)?;
     Ok(*round)
@@ -853,13 +1823,27 @@ async fn get_config(rpc: &RpcClient) -> Result<Config, anyhow::Error> {
     Ok(*config)
 }
 
-async fn get_miner(rpc: &RpcClient, authority: Pubkey) -> Result<Miner, anyhow::Error> {
-    let miner_pda = speedway_api::state::miner_pda(authority);
+async fn get_pending_change(rpc: &RpcClient, id: u64) -> Result<PendingChange, anyhow::Error> {
+    let pending_change_pda = speedway_api::state::pending_change_pda(id);
+    let account = rpc.get_account(&pending_change_pda.0).await?;
+    let pending_change = PendingChange::try_from_bytes(&account.data)?;
+    Ok(*pending_change)
+}
+
+async fn get_miner(rpc: &RpcClient, authority: Pubkey, table_id: u8) -> Result<Miner, anyhow::Error> {
+    let miner_pda = speedway_api::state::miner_pda(authority, table_id);
     let account = rpc.get_account(&miner_pda.0).await?;
     let miner = Miner::try_from_bytes(&account.data)?;
     Ok(*miner)
 }
 
+async fn get_garage(rpc: &RpcClient, authority: Pubkey) -> Result<Garage, anyhow::Error> {
+    let garage_pda = speedway_api::state::garage_pda(authority);
+    let account = rpc.get_account(&garage_pda.0).await?;
+    let garage = Garage::try_from_bytes(&account.data)?;
+    Ok(*garage)
+}
+
 async fn get_clock(rpc: &RpcClient) -> Result<Clock, anyhow::Error> {
     let data = rpc.get_account_data(&solana_sdk::sysvar::clock::ID).await?;
     let clock = bincode::deserialize::<Clock>(&data)?;
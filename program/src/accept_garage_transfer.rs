@@ -0,0 +1,151 @@
+use speedway_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::fuel_up::{migrate_garage, resolve_garage};
+
+/// Accepts a pending Garage transfer, moving the position to the signer.
+///
+/// The full position (deposits, claims, accrual clock, cycle history) moves into the
+/// recipient's Garage PDA, which must not already exist. The referrer is preserved, along
+/// with direct_referrals and lifetime_ref_earnings, so the upstream referrer's relationship
+/// count is unchanged. If the recipient is the Garage's own referrer, the referrer falls back
+/// to the house to avoid self-referral, and the direct referral moves from the recipient's
+/// Garage (reached through the recipient's GarageForward, passed as remaining accounts) to the
+/// house Garage. The old Garage and proposal are closed and their rent returned to the
+/// previous owner.
+///
+/// A GarageForward record pointing from the previous owner to the recipient is left behind,
+/// so referees that still store the previous owner as their referrer keep paying referral
+/// shares to the position. FuelUp follows it for as long as the previous owner has no Garage.
+pub fn process_accept_garage_transfer(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, authority_info, garage_info, garage_transfer_info, garage_forward_info, recipient_garage_info, house_garage_info, board_info, system_program, ore_program, referrer_upline_infos @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    authority_info.is_writable()?;
+    garage_transfer_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_TRANSFER, &authority_info.key.to_bytes()], &speedway_api::ID)?
+        .as_account::<GarageTransfer>(&speedway_api::ID)?
+        .assert_err(
+            |t| t.authority == *authority_info.key && t.recipient == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    garage_info
+        .is_writable()?
+        .has_seeds(&[GARAGE, &authority_info.key.to_bytes()], &speedway_api::ID)?;
+    migrate_garage(garage_info, signer_info)?;
+    let garage = *garage_info
+        .as_account::<Garage>(&speedway_api::ID)?
+        .assert(|g| g.authority == *authority_info.key)?;
+    garage_forward_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_FORWARD, &authority_info.key.to_bytes()], &speedway_api::ID)?;
+    recipient_garage_info
+        .is_writable()?
+        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    house_garage_info
+        .is_writable()?
+        .has_seeds(&[GARAGE, &HOUSE_ADDRESS.to_bytes()], &speedway_api::ID)?;
+    board_info.as_account::<Board>(&speedway_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&speedway_api::ID)?;
+
+    // Refuse to merge into an existing Garage.
+    if !recipient_garage_info.data_is_empty() {
+        return Err(OreError::GarageAlreadyExists.into());
+    }
+
+    // Move the direct referral to the house if the recipient referred this Garage.
+    if garage.referrer == *signer_info.key {
+        let mut referrer = *signer_info.key;
+        let mut upline_infos = referrer_upline_infos.iter();
+        if let Some(referrer_garage_info) = resolve_garage(
+            authority_info.key,
+            &mut referrer,
+            recipient_garage_info,
+            &mut upline_infos,
+        )? {
+            migrate_garage(referrer_garage_info, signer_info)?;
+            let referrer_garage =
+                referrer_garage_info.as_account_mut::<Garage>(&speedway_api::ID)?;
+            referrer_garage.direct_referrals = referrer_garage.direct_referrals.saturating_sub(1);
+            referrer_garage.level_referrals[0] =
+                referrer_garage.level_referrals[0].saturating_sub(1);
+        }
+        migrate_garage(house_garage_info, signer_info)?;
+        let house_garage = house_garage_info.as_account_mut::<Garage>(&speedway_api::ID)?;
+        house_garage.direct_referrals = house_garage
+            .direct_referrals
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        house_garage.level_referrals[0] = house_garage.level_referrals[0]
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    // Create recipient Garage and move the position.
+    create_program_account::<Garage>(
+        recipient_garage_info,
+        system_program,
+        signer_info,
+        &speedway_api::ID,
+        &[GARAGE, &signer_info.key.to_bytes()],
+    )?;
+    let recipient_garage = recipient_garage_info.as_account_mut::<Garage>(&speedway_api::ID)?;
+    *recipient_garage = garage;
+    recipient_garage.authority = *signer_info.key;
+    if recipient_garage.referrer == *signer_info.key {
+        recipient_garage.referrer = HOUSE_ADDRESS;
+    }
+
+    // Forward the previous owner's referrals to the recipient.
+    let garage_forward = if garage_forward_info.data_is_empty() {
+        create_program_account::<GarageForward>(
+            garage_forward_info,
+            system_program,
+            signer_info,
+            &speedway_api::ID,
+            &[GARAGE_FORWARD, &authority_info.key.to_bytes()],
+        )?;
+        let garage_forward =
+            garage_forward_info.as_account_mut::<GarageForward>(&speedway_api::ID)?;
+        garage_forward.authority = *authority_info.key;
+        garage_forward
+    } else {
+        garage_forward_info.as_account_mut::<GarageForward>(&speedway_api::ID)?
+    };
+    garage_forward.recipient = *signer_info.key;
+    garage_forward.created_at = clock.unix_timestamp;
+
+    // Close old Garage and proposal.
+    garage_info.close(authority_info)?;
+    garage_transfer_info.close(authority_info)?;
+
+    // Emit event.
+    program_log(
+        &[board_info.clone(), ore_program.clone()],
+        GarageTransferEvent {
+            disc: OreEvent::GarageTransfer as u64,
+            from: *authority_info.key,
+            to: *signer_info.key,
+            referrer: recipient_garage.referrer,
+            total_deposited: recipient_garage.total_deposited,
+            total_claimed: recipient_garage.total_claimed,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    sol_log(&format!(
+        "🔑 Garage transferred: {} → {}",
+        authority_info.key, signer_info.key
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Accepts a pending role transfer as the recipient.
+///
+/// The Config timelock delay must have elapsed since the proposal. The proposal rent is
+/// returned to its proposer.
+pub fn process_accept_role_transfer(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = AcceptRoleTransfer::try_from_bytes(data)?;
+    let role = Role::try_from(args.role).map_err(|_| ProgramError::InvalidArgument)?;
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, role_transfer_info, authority_info, board_info, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account_mut::<Config>(&speedway_api::ID)?;
+    let transfer = *role_transfer_info
+        .is_writable()?
+        .has_seeds(&[ROLE_TRANSFER, &[role as u8]], &speedway_api::ID)?
+        .as_account::<RoleTransfer>(&speedway_api::ID)?
+        .assert_err(
+            |t| t.recipient == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?
+        .assert_err(
+            |t| t.is_ready(clock.unix_timestamp, config.timelock_delay),
+            OreError::TimelockNotElapsed.into(),
+        )?;
+    authority_info
+        .is_writable()?
+        .has_address(&transfer.authority)?;
+    board_info.as_account::<Board>(&speedway_api::ID)?;
+    ore_program.is_program(&speedway_api::ID)?;
+
+    // Transfer role.
+    let from = config.role(role);
+    config.set_role(role, *signer_info.key);
+
+    // Close proposal.
+    role_transfer_info.close(authority_info)?;
+
+    // Emit event.
+    program_log(
+        &[board_info.clone(), ore_program.clone()],
+        RoleTransferEvent {
+            disc: OreEvent::RoleTransfer as u64,
+            role: role as u64,
+            from,
+            to: *signer_info.key,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
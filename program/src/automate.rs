@@ -2,6 +2,10 @@ use speedway_api::prelude::*;
 use steel::*;
 
 /// Sets the executor.
+///
+/// Reconfiguring resets the spend and win tracking used by the StopLoss strategy and clears
+/// a stop, so the authority resumes a stopped automation by calling Automate again. It also
+/// restarts the round count used by `max_rounds`.
 pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = Automate::try_from_bytes(data)?;
@@ -11,20 +15,36 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     let mask = u64::from_le_bytes(args.mask);
     let strategy = AutomationStrategy::from_u64(args.strategy as u64);
     let reload = u64::from_le_bytes(args.reload) > 0;
+    let round_budget = u64::from_le_bytes(args.round_budget);
+    let daily_budget = u64::from_le_bytes(args.daily_budget);
+    let stop_loss = u64::from_le_bytes(args.stop_loss);
+    let max_rounds = u64::from_le_bytes(args.max_rounds);
+    let expires_at_slot = u64::from_le_bytes(args.expires_at_slot);
 
     // Load accounts.
-    let [signer_info, automation_info, executor_info, miner_info, system_program] = accounts else {
+    let clock = Clock::get()?;
+    let [signer_info, automation_info, executor_info, miner_info, system_program, config_info] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     automation_info.is_writable()?;
     system_program.is_program(&system_program::ID)?;
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?;
 
     // Do not allow permissionless execution with discretionary strategy.
     if strategy == AutomationStrategy::Discretionary && *executor_info.key == EXECUTOR_ADDRESS {
         return Err(OreError::InvalidExecutor.into());
     }
 
+    // Expiry must be in the future.
+    if expires_at_slot > 0 && expires_at_slot <= clock.slot {
+        return Err(OreError::InvalidParams.into());
+    }
+
     // Open miner account.
     let miner = if miner_info.data_is_empty() {
         create_program_account::<Miner>(
@@ -55,6 +75,9 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
             )?
     };
 
+    // Migrate automation to the current layout.
+    migrate_automation(automation_info, signer_info)?;
+
     // Close account if executor is Pubkey::default().
     if *executor_info.key == Pubkey::default() {
         automation_info
@@ -97,11 +120,22 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     automation.mask = mask;
     automation.strategy = strategy as u64;
     automation.reload = reload as u64;
+    automation.round_budget = round_budget;
+    automation.daily_budget = daily_budget;
+    automation.stop_loss = stop_loss;
+    automation.total_spent = 0;
+    automation.total_won = 0;
+    automation.rewards_seen = miner.lifetime_rewards_sol;
+    automation.rewards_table_id = DEFAULT_TABLE_ID as u64;
+    automation.stopped = 0;
+    automation.max_rounds = max_rounds;
+    automation.rounds_played = 0;
+    automation.expires_at_slot = expires_at_slot;
 
     // Top up checkpoint fee.
     if miner.checkpoint_fee == 0 {
-        miner.checkpoint_fee = CHECKPOINT_FEE;
-        miner_info.collect(CHECKPOINT_FEE, &signer_info)?;
+        miner.checkpoint_fee = config.params.checkpoint_fee;
+        miner_info.collect(config.params.checkpoint_fee, &signer_info)?;
     }
 
     // Transfer balance to executor.
@@ -109,3 +143,39 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
 
     Ok(())
 }
+
+/// Migrates an Automation account to the current layout, with `payer_info` covering the rent.
+pub fn migrate_automation(
+    automation_info: &AccountInfo<'_>,
+    payer_info: &AccountInfo<'_>,
+) -> ProgramResult {
+    let automation_len = 8 + std::mem::size_of::<Automation>();
+    if automation_info.data_is_empty() || automation_info.data_len() >= automation_len {
+        return Ok(());
+    }
+    let required = Rent::get()?.minimum_balance(automation_len);
+    let current = Rent::get()?.minimum_balance(automation_info.data_len());
+    automation_info.collect(required.saturating_sub(current), payer_info)?;
+    automation_info.realloc(automation_len, true)?;
+    Ok(())
+}
+
+/// Migrates an Automation account to the current layout, paying the rent from its own balance.
+///
+/// Used on executor paths, so keepers are never charged for an authority's upgrade. The
+/// account already holds its balance in lamports, so the rent is carved out of `balance`.
+pub fn migrate_automation_from_balance(automation_info: &AccountInfo<'_>) -> ProgramResult {
+    let automation_len = 8 + std::mem::size_of::<Automation>();
+    if automation_info.data_is_empty() || automation_info.data_len() >= automation_len {
+        return Ok(());
+    }
+    let required = Rent::get()?.minimum_balance(automation_len);
+    let current = Rent::get()?.minimum_balance(automation_info.data_len());
+    let rent = required.saturating_sub(current);
+    automation_info.realloc(automation_len, true)?;
+    let automation = automation_info
+        .as_account_mut::<Automation>(&speedway_api::ID)?
+        .assert_mut_err(|a| a.balance >= rent, OreError::AutomationUnderfunded.into())?;
+    automation.balance -= rent;
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+use crate::fuel_up::migrate_garage;
+
+/// Opens, updates or closes a Garage automation.
+pub fn process_automate_garage(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = AutomateGarage::try_from_bytes(data)?;
+    let deposit = u64::from_le_bytes(args.deposit);
+    let fee = u64::from_le_bytes(args.fee);
+    let interval = u64::from_le_bytes(args.interval);
+    let boost_count = u64::from_le_bytes(args.boost_count);
+    let strategy = GarageAutomationStrategy::try_from(args.strategy)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    // Load accounts.
+    let [signer_info, garage_automation_info, executor_info, garage_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    garage_automation_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_AUTOMATION, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Close account if executor is Pubkey::default().
+    if *executor_info.key == Pubkey::default() {
+        garage_automation_info
+            .as_account_mut::<GarageAutomation>(&speedway_api::ID)?
+            .assert_mut_err(
+                |a| a.authority == *signer_info.key,
+                OreError::NotAuthorized.into(),
+            )?;
+        garage_automation_info.close(signer_info)?;
+        return Ok(());
+    }
+
+    // Garage must exist to be automated.
+    if garage_info.data_is_empty() {
+        return Err(OreError::GarageRequired.into());
+    }
+    garage_info
+        .is_writable()?
+        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    migrate_garage(garage_info, signer_info)?;
+    garage_info
+        .as_account::<Garage>(&speedway_api::ID)?
+        .assert(|g| g.authority == *signer_info.key)?;
+
+    // Create automation.
+    let automation = if garage_automation_info.data_is_empty() {
+        create_program_account::<GarageAutomation>(
+            garage_automation_info,
+            system_program,
+            signer_info,
+            &speedway_api::ID,
+            &[GARAGE_AUTOMATION, &signer_info.key.to_bytes()],
+        )?;
+        let automation =
+            garage_automation_info.as_account_mut::<GarageAutomation>(&speedway_api::ID)?;
+        automation.authority = *signer_info.key;
+        automation.balance = 0;
+        automation.boosts_since_collect = 0;
+        automation.last_executed_at = 0;
+        automation
+    } else {
+        garage_automation_info
+            .as_account_mut::<GarageAutomation>(&speedway_api::ID)?
+            .assert_mut_err(
+                |a| a.authority == *signer_info.key,
+                OreError::NotAuthorized.into(),
+            )?
+    };
+
+    // Set policy.
+    automation.balance += deposit;
+    automation.executor = *executor_info.key;
+    automation.fee = fee;
+    automation.strategy = strategy as u64;
+    automation.interval = interval;
+    automation.boost_count = boost_count;
+
+    // Transfer fee reserve to automation.
+    garage_automation_info.collect(deposit, signer_info)?;
+
+    Ok(())
+}
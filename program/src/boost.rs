@@ -3,18 +3,34 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::fuel_up::migrate_garage;
+
 /// Boost: Compound accrued Garage rewards back into total_deposited.
 ///
 /// This increases the user's max_payout potential (365% of total_deposited).
-/// Subject to a 5% boost tax which goes to the treasury garage_pool.
+/// Subject to a 5% boost tax: 2% is minted to the team. The 3% pool share is retained by
+/// never crediting it to deposits; like the pool shares of Collect, it is not minted, so
+/// Boost adds no supply beyond the team fee.
+///
+/// A partial amount may be compounded; the remainder keeps accruing.
+///
+/// The signer is either the Garage authority or the executor of its GarageAutomation.
+/// Executors may only boost when the automation policy allows it, always boost the full
+/// available amount, and are paid the automation fee.
 ///
 /// Rewards are calculated based on:
-/// - 1% daily yield on total_deposited
+/// - The configured daily yield on total_deposited
 /// - Capped at remaining payout (max_payout - total_claimed)
-pub fn process_boost(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_boost(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Boost::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, garage_info, treasury_info, board_info, ore_program] = accounts else {
+    let [signer_info, authority_info, garage_info, garage_automation_info, config_info, treasury_info, garage_vault_info, mint_info, team_tokens_info, board_info, system_program, token_program, ore_program] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -22,46 +38,133 @@ pub fn process_boost(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     signer_info.is_signer()?;
 
     // Validate garage PDA.
-    let garage = garage_info
+    garage_info
         .is_writable()?
-        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?
-        .as_account_mut::<Garage>(&speedway_api::ID)?
-        .assert_mut(|g| g.authority == *signer_info.key)?;
+        .has_seeds(&[GARAGE, &authority_info.key.to_bytes()], &speedway_api::ID)?;
+
+    // Validate garage automation PDA.
+    garage_automation_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_AUTOMATION, &authority_info.key.to_bytes()], &speedway_api::ID)?;
+
+    // Validate config.
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(|c| !c.is_paused(PAUSE_GARAGE_DEPOSIT), OreError::Paused.into())?
+        .assert_err(
+            |c| c.fee_collector != Pubkey::default(),
+            OreError::RoleNotSet.into(),
+        )?;
 
     // Validate treasury.
     let treasury = treasury_info
         .is_writable()?
+        .has_seeds(&[TREASURY], &speedway_api::ID)?
         .as_account_mut::<Treasury>(&speedway_api::ID)?;
 
+    // Validate Garage vault.
+    garage_vault_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_VAULT], &speedway_api::ID)?
+        .as_token_account()?
+        .assert(|t| t.mint() == MINT_ADDRESS)?
+        .assert(|t| t.owner() == *treasury_info.key)?;
+
+    // Validate mint.
+    mint_info.has_address(&MINT_ADDRESS)?.is_writable()?;
+
+    // Validate team token account.
+    team_tokens_info
+        .is_writable()?
+        .as_associated_token_account(&config.fee_collector, &MINT_ADDRESS)?;
+
     // Validate board (for logging).
     board_info.as_account::<Board>(&speedway_api::ID)?;
 
-    // Validate program.
+    // Validate programs.
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
     ore_program.is_program(&speedway_api::ID)?;
 
+    // Check if signer is the garage automation executor.
+    let mut amount = amount;
+    if *signer_info.key != *authority_info.key {
+        let automation = garage_automation_info
+            .as_account_mut::<GarageAutomation>(&speedway_api::ID)?
+            .assert_mut_err(
+                |a| a.authority == *authority_info.key,
+                OreError::NotAuthorized.into(),
+            )?
+            .assert_mut_err(
+                |a| a.executor == *signer_info.key || a.executor == EXECUTOR_ADDRESS,
+                OreError::InvalidExecutor.into(),
+            )?
+            .assert_mut_err(
+                |a| a.can_boost(clock.unix_timestamp),
+                OreError::AutomationNotReady.into(),
+            )?
+            .assert_mut_err(
+                |a| a.balance >= a.fee,
+                OreError::AutomationUnderfunded.into(),
+            )?;
+
+        // Keepers always compound the full available amount.
+        amount = 0;
+
+        // Migrate the garage, with the automation balance covering the rent.
+        let rent = migrate_garage(garage_info, garage_automation_info)?;
+        let cost = automation
+            .fee
+            .checked_add(rent)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if automation.balance < cost {
+            return Err(OreError::AutomationUnderfunded.into());
+        }
+
+        // Record execution and pay executor.
+        automation.record_boost(clock.unix_timestamp);
+        automation.balance -= cost;
+        garage_automation_info.send(automation.fee, signer_info);
+    } else {
+        migrate_garage(garage_info, signer_info)?;
+    }
+
+    // Load garage.
+    let garage = garage_info
+        .as_account_mut::<Garage>(&speedway_api::ID)?
+        .assert_mut(|g| g.authority == *authority_info.key)?;
+
     // Check if garage is exhausted.
     if garage.is_exhausted() {
         return Err(OreError::GarageExhausted.into());
     }
 
     // Calculate available rewards.
-    let available = garage.calculate_available(clock.unix_timestamp);
+    let available =
+        garage.calculate_available(clock.unix_timestamp, config.params.daily_rate_bps);
     if available == 0 {
         return Err(OreError::NoRewardsAvailable.into());
     }
 
-    // Calculate boost tax (5% of available rewards).
-    let tax = available
-        .checked_mul(BOOST_TAX_BPS)
-        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Determine amount to compound (0 = all available). The remainder keeps accruing.
+    let amount = if amount == 0 { available } else { amount };
+    if amount > available {
+        return Err(OreError::AmountExceedsAvailable.into());
+    }
 
-    // Net amount to add to deposits.
-    let net_amount = available
-        .checked_sub(tax)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Apply boost tax (5% of amount).
+    let BoostTax {
+        tax,
+        team_fee,
+        pool_fee,
+        net_amount,
+    } = apply_boost_tax(amount)?;
 
     // Update garage.
+    treasury.track_garage(garage)?;
+    let outstanding_before = garage.outstanding();
+
     // Add net amount to total_deposited (this increases max_payout).
     garage.total_deposited = garage
         .total_deposited
@@ -71,36 +174,49 @@ pub fn process_boost(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     // Mark rewards as claimed (they were converted to deposits).
     garage.total_claimed = garage
         .total_claimed
-        .checked_add(available)
+        .checked_add(amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    // Update last action timestamp.
-    garage.last_action_at = clock.unix_timestamp;
+    // Reset accrual clock, carrying forward any rewards left unclaimed.
+    garage.settle(
+        clock.unix_timestamp,
+        available - amount,
+        config.params.daily_rate_bps,
+    );
 
     // Recalculate max_payout with new total_deposited.
-    garage.update_max_payout();
+    garage.update_max_payout(config.params.max_payout_mult);
 
-    // Add tax to treasury garage pool.
-    treasury.garage_pool = treasury
-        .garage_pool
-        .checked_add(tax)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Update treasury total garage TVL (net amount was added to deposits).
+    // Update treasury Garage totals (net amount was added to deposits).
+    treasury.update_garage_outstanding(outstanding_before, garage.outstanding())?;
     treasury.total_garage_tvl = treasury
         .total_garage_tvl
         .checked_add(net_amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
+    // Mint team share of tax to team token account.
+    if team_fee > 0 {
+        mint_to_signed(
+            mint_info,
+            team_tokens_info,
+            treasury_info,
+            token_program,
+            team_fee,
+            &[TREASURY],
+        )?;
+    }
+
     // Emit event.
     program_log(
         &[board_info.clone(), ore_program.clone()],
         BoostEvent {
             disc: OreEvent::Boost as u64,
-            authority: *signer_info.key,
-            gross_amount: available,
+            authority: *authority_info.key,
+            gross_amount: amount,
             net_amount,
             tax,
+            team_fee,
+            pool_fee,
             new_total_deposited: garage.total_deposited,
             new_max_payout: garage.max_payout,
             ts: clock.unix_timestamp,
@@ -109,11 +225,48 @@ pub fn process_boost(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     )?;
 
     sol_log(&format!(
-        "🚀 Boost: {} FUEL compounded (net: {}, tax: {})",
-        amount_to_ui_amount(available, TOKEN_DECIMALS),
+        "🚀 Boost: {} FUEL compounded (net: {}, tax: {}, team: {}, pool: {})",
+        amount_to_ui_amount(amount, TOKEN_DECIMALS),
         amount_to_ui_amount(net_amount, TOKEN_DECIMALS),
         amount_to_ui_amount(tax, TOKEN_DECIMALS),
+        amount_to_ui_amount(team_fee, TOKEN_DECIMALS),
+        amount_to_ui_amount(pool_fee, TOKEN_DECIMALS),
     ));
 
     Ok(())
 }
+
+/// The split of a compounded amount under the boost tax.
+pub struct BoostTax {
+    pub tax: u64,
+    pub team_fee: u64,
+    pub pool_fee: u64,
+    pub net_amount: u64,
+}
+
+/// Applies the boost tax to `amount`: 2% of gross to team, the remainder (3%) to pool.
+pub fn apply_boost_tax(amount: u64) -> Result<BoostTax, ProgramError> {
+    let tax = amount
+        .checked_mul(BOOST_TAX_BPS)
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let team_fee = amount
+        .checked_mul(BOOST_TEAM_BPS)
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let pool_fee = tax
+        .checked_sub(team_fee)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Net amount to add to deposits.
+    let net_amount = amount
+        .checked_sub(tax)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(BoostTax {
+        tax,
+        team_fee,
+        pool_fee,
+        net_amount,
+    })
+}
@@ -16,12 +16,19 @@ use steel::*;
 pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Load accounts.
     let (ore_accounts, swap_accounts) = accounts.split_at(9);
-    let [signer_info, board_info, _config_info, mint_info, treasury_info, treasury_fuel_info, treasury_sol_info, token_program, ore_program] =
+    let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_fuel_info, treasury_sol_info, token_program, ore_program] =
         ore_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?.has_address(&BURY_AUTHORITY)?;
+    signer_info.is_signer()?;
+    config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(
+            |c| c.has_role(Role::TreasuryOperator, signer_info.key),
+            OreError::NotAuthorized.into(),
+        )?;
     board_info.as_account_mut::<Board>(&speedway_api::ID)?;
     let fuel_mint = mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     let _treasury = treasury_info.as_account_mut::<Treasury>(&speedway_api::ID)?;
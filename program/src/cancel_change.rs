@@ -0,0 +1,53 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Cancels a pending change. Only callable by the admin.
+///
+/// The account rent is returned to the proposer.
+pub fn process_cancel_change(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, pending_change_info, proposer_info, board_info, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    let pending_change = *pending_change_info
+        .is_writable()?
+        .as_account::<PendingChange>(&speedway_api::ID)?;
+    pending_change_info.has_seeds(
+        &[PENDING_CHANGE, &pending_change.id.to_le_bytes()],
+        &speedway_api::ID,
+    )?;
+    proposer_info
+        .is_writable()?
+        .has_address(&pending_change.proposer)?;
+    board_info.as_account::<Board>(&speedway_api::ID)?;
+    ore_program.is_program(&speedway_api::ID)?;
+
+    // Close pending change.
+    pending_change_info.close(proposer_info)?;
+
+    // Emit event.
+    program_log(
+        &[board_info.clone(), ore_program.clone()],
+        ChangeCancelledEvent {
+            disc: OreEvent::ChangeCancelled as u64,
+            id: pending_change.id,
+            kind: pending_change.kind,
+            authority: *signer_info.key,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
@@ -3,17 +3,33 @@ use solana_program::{log::sol_log, native_token::lamports_to_sol, rent::Rent};
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::deploy::migrate_round;
+
 /// Checkpoints a miner's rewards.
-pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_checkpoint(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Checkpoint::try_from_bytes(data)?;
+    let table_id = args.table_id;
+
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, board_info, miner_info, round_info, treasury_info, system_program] = accounts
+    let [signer_info, board_info, config_info, miner_info, round_info, treasury_info, system_program] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    let board = board_info.as_account::<Board>(&speedway_api::ID)?;
+    let board = board_info
+        .has_seeds(&[BOARD, table_seed(&table_id)], &speedway_api::ID)?
+        .as_account::<Board>(&speedway_api::ID)?;
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?;
     let miner = miner_info.as_account_mut::<Miner>(&speedway_api::ID)?;
+    miner_info.has_seeds(
+        &[MINER, &miner.authority.to_bytes(), table_seed(&table_id)],
+        &speedway_api::ID,
+    )?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&speedway_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
@@ -25,14 +41,28 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     // If round account is empty, verify the correct account was provided.
     // This can happen if the miner attempted to checkpoint after the round expired and the account was closed.
     // In this case, the miner forfeits any potential rewards.
+    let round_id_bytes = miner.round_id.to_le_bytes();
+    let round_seeds: &[&[u8]] = &[ROUND, table_seed(&table_id), &round_id_bytes];
     if round_info.data_is_empty() {
         sol_log(&format!("Round account is empty").as_str());
-        round_info.has_seeds(&[ROUND, &miner.round_id.to_le_bytes()], &speedway_api::ID)?;
+        round_info.has_seeds(round_seeds, &speedway_api::ID)?;
         miner.checkpoint_id = miner.round_id;
         return Ok(());
     }
 
-    // If round is current round, or the miner round ID does not match the provided round, return.
+    // If the provided round is not the miner's round at this table, return.
+    if round_info.has_seeds(round_seeds, &speedway_api::ID).is_err() {
+        sol_log(&format!("Round not valid").as_str());
+        return Ok(());
+    }
+    migrate_round(
+        round_info,
+        signer_info,
+        board.intermission_slots(&config.params),
+        config.params.checkpoint_window_slots(),
+    )?;
+
+    // If round is current round, or has not been reset, return.
     let round = round_info.as_account_mut::<Round>(&speedway_api::ID)?; // Round has been closed.
     sol_log(&format!("Round ID: {}", round.id).as_str());
     if round.id == board.round_id || round.id != miner.round_id || round.slot_hash == [0; 32] {
@@ -49,9 +79,9 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     }
 
     // Calculate bot fee.
-    // If the round expires in less than 12h, anyone may checkpoint this account and collect the bot fee.
+    // In the last half of the claim window, anyone may checkpoint this account and collect the bot fee.
     let mut bot_fee = 0;
-    if clock.slot >= round.expires_at - TWELVE_HOURS_SLOTS {
+    if clock.slot >= round.expires_at.saturating_sub(round.checkpoint_window_slots) {
         bot_fee = miner.checkpoint_fee;
         miner.checkpoint_fee = 0;
     }
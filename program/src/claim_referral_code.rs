@@ -0,0 +1,66 @@
+use speedway_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::fuel_up::migrate_garage;
+
+/// Claims a vanity referral code pointing to the signer's Garage.
+///
+/// The code must be normalized (uppercase ASCII alphanumerics, zero-padded) and the
+/// signer must own a Garage, since only Garage owners can receive referral fees.
+pub fn process_claim_referral_code(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = ClaimReferralCode::try_from_bytes(data)?;
+    if !ReferralCode::is_normalized(&args.code) {
+        return Err(OreError::InvalidReferralCode.into());
+    }
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, referral_code_info, garage_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    referral_code_info
+        .is_writable()?
+        .has_seeds(&[REFERRAL_CODE, &args.code], &speedway_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Garage must exist to receive referrals.
+    if garage_info.data_is_empty() {
+        return Err(OreError::GarageRequired.into());
+    }
+    garage_info
+        .is_writable()?
+        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    migrate_garage(garage_info, signer_info)?;
+    garage_info
+        .as_account::<Garage>(&speedway_api::ID)?
+        .assert(|g| g.authority == *signer_info.key)?;
+
+    // Code must not already be claimed.
+    if !referral_code_info.data_is_empty() {
+        return Err(OreError::ReferralCodeTaken.into());
+    }
+
+    // Create referral code.
+    create_program_account::<ReferralCode>(
+        referral_code_info,
+        system_program,
+        signer_info,
+        &speedway_api::ID,
+        &[REFERRAL_CODE, &args.code],
+    )?;
+    let referral_code = referral_code_info.as_account_mut::<ReferralCode>(&speedway_api::ID)?;
+    referral_code.code = args.code;
+    referral_code.authority = *signer_info.key;
+    referral_code.created_at = clock.unix_timestamp;
+
+    sol_log(&format!(
+        "🏷️ Referral code {} claimed by {}",
+        referral_code.code_str(),
+        signer_info.key
+    ));
+
+    Ok(())
+}
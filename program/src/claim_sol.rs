@@ -6,13 +6,17 @@ use steel::*;
 pub fn process_claim_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, miner_info, system_program] = accounts else {
+    let [signer_info, miner_info, config_info, system_program] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     let miner = miner_info
         .as_account_mut::<Miner>(&speedway_api::ID)?
         .assert_mut(|m| m.authority == *signer_info.key)?;
+    config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(|c| !c.is_paused(PAUSE_CLAIM), OreError::Paused.into())?;
     system_program.is_program(&system_program::ID)?;
 
     // Normalize amount.
@@ -16,7 +16,7 @@ use steel::*;
 pub fn process_claim_wallet(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, miner_info, treasury_info, mint_info, recipient_info, team_tokens_info, board_info, system_program, token_program, associated_token_program, ore_program] =
+    let [signer_info, miner_info, config_info, treasury_info, mint_info, recipient_info, team_tokens_info, board_info, system_program, token_program, associated_token_program, ore_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -25,13 +25,22 @@ pub fn process_claim_wallet(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Progr
     // Validate signer.
     signer_info.is_signer()?;
 
-    // Validate miner PDA.
+    // Validate miner (the signer's miner at any table).
     let miner = miner_info
         .is_writable()?
-        .has_seeds(&[MINER, &signer_info.key.to_bytes()], &speedway_api::ID)?
         .as_account_mut::<Miner>(&speedway_api::ID)?
         .assert_mut(|m| m.authority == *signer_info.key)?;
 
+    // Validate config.
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(|c| !c.is_paused(PAUSE_CLAIM), OreError::Paused.into())?
+        .assert_err(
+            |c| c.fee_collector != Pubkey::default(),
+            OreError::RoleNotSet.into(),
+        )?;
+
     // Validate treasury.
     let treasury = treasury_info
         .is_writable()?
@@ -44,7 +53,7 @@ pub fn process_claim_wallet(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Progr
     // Validate team token account.
     team_tokens_info
         .is_writable()?
-        .as_associated_token_account(&ADMIN_FEE_COLLECTOR, &MINT_ADDRESS)?;
+        .as_associated_token_account(&config.fee_collector, &MINT_ADDRESS)?;
 
     // Validate board (for logging).
     board_info.as_account::<Board>(&speedway_api::ID)?;
@@ -2,8 +2,14 @@ use speedway_api::prelude::*;
 use solana_program::rent::Rent;
 use steel::*;
 
+use crate::deploy::migrate_round;
+
 /// Closes a round accound, and returns the rent to the rent payer.
-pub fn process_close(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_close(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Close::try_from_bytes(data)?;
+    let table_id = args.table_id;
+
     // Load accounts.
     let clock = Clock::get()?;
     let [signer_info, board_info, rent_payer_info, round_info, treasury_info, system_program] =
@@ -12,13 +18,33 @@ pub fn process_close(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    let board = board_info.as_account_mut::<Board>(&speedway_api::ID)?;
+    let board = board_info
+        .has_seeds(&[BOARD, table_seed(&table_id)], &speedway_api::ID)?
+        .as_account_mut::<Board>(&speedway_api::ID)?;
     rent_payer_info.is_writable()?;
-    round_info
+
+    // Rounds from before the timing snapshot are grown so they can be loaded. The snapshot
+    // is never read once a round expires, so it is left zero.
+    if round_info.data_len() >= 16 && round_info.data_len() < 8 + std::mem::size_of::<Round>() {
+        let round_id = round_info.try_borrow_data()?[8..16]
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        round_info.is_writable()?.has_seeds(
+            &[ROUND, table_seed(&table_id), &round_id.to_le_bytes()],
+            &speedway_api::ID,
+        )?;
+        migrate_round(round_info, signer_info, 0, 0)?;
+    }
+    let round = round_info
         .as_account_mut::<Round>(&speedway_api::ID)?
         .assert_mut(|r| r.id < board.round_id)?
         .assert_mut(|r| r.expires_at < clock.slot)? // Ensure round has expired.
         .assert_mut(|r| r.rent_payer == *rent_payer_info.key)?; // Ensure the rent payer is the correct one.
+    round_info.has_seeds(
+        &[ROUND, table_seed(&table_id), &round.id.to_le_bytes()],
+        &speedway_api::ID,
+    )?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&speedway_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
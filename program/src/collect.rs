@@ -3,28 +3,41 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::fuel_up::migrate_garage;
+
 /// Collect: Withdraw accrued Garage rewards to user's wallet.
 ///
 /// Tax structure:
-/// 1. Base collect tax: 10% of gross amount → goes to garage_pool
-/// 2. Whale tax (applied AFTER base tax): 0-50% based on user's TVL %
+/// 1. Base collect tax: 10% of gross amount → 2% to team, 8% to pool
+/// 2. Whale tax (applied AFTER base tax): 0-50% based on user's share of outstanding TVL
 ///    - <1% of TVL: 0% whale tax
 ///    - >=1% of TVL: 5% whale tax
 ///    - >=2% of TVL: 10% whale tax
 ///    - ... (5% increment per 1% TVL tier)
 ///    - >=10% of TVL: 50% whale tax (max)
-///    - Whale tax distribution: 10% to team, 90% to pool
+///    - Whale tax distribution: 30% to team, 70% to pool
+///
+/// Rewards are paid from the Garage vault, where the pool portions of the taxes remain.
+/// If the vault is insufficient, FUEL is minted as a backstop up to the cap in Config.
+/// Once the cap is reached, Collect fails with InsufficientPoolBalance.
+///
+/// A partial amount may be withdrawn; the remainder keeps accruing.
 ///
-/// Rewards are paid from the treasury garage_pool.
-/// If the pool is insufficient, FUEL is minted as a backstop.
+/// The signer is either the Garage authority or the executor of its GarageAutomation.
+/// Executors may only collect when the automation policy allows it, always collect the full
+/// available amount to the authority's wallet, and are paid the automation fee.
 ///
 /// Rewards are calculated based on:
 /// - 1% daily yield on total_deposited
 /// - Capped at remaining payout (max_payout - total_claimed)
-pub fn process_collect(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_collect(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Collect::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, garage_info, treasury_info, treasury_tokens_info, recipient_info, mint_info, team_tokens_info, board_info, system_program, token_program, associated_token_program, ore_program] =
+    let [signer_info, authority_info, garage_info, garage_automation_info, config_info, treasury_info, garage_vault_info, recipient_info, mint_info, team_tokens_info, board_info, system_program, token_program, associated_token_program, ore_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -34,11 +47,24 @@ pub fn process_collect(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramRes
     signer_info.is_signer()?;
 
     // Validate garage PDA.
-    let garage = garage_info
+    garage_info
         .is_writable()?
-        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?
-        .as_account_mut::<Garage>(&speedway_api::ID)?
-        .assert_mut(|g| g.authority == *signer_info.key)?;
+        .has_seeds(&[GARAGE, &authority_info.key.to_bytes()], &speedway_api::ID)?;
+
+    // Validate garage automation PDA.
+    garage_automation_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_AUTOMATION, &authority_info.key.to_bytes()], &speedway_api::ID)?;
+
+    // Validate config.
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(|c| !c.is_paused(PAUSE_GARAGE_WITHDRAW), OreError::Paused.into())?
+        .assert_err(
+            |c| c.fee_collector != Pubkey::default(),
+            OreError::RoleNotSet.into(),
+        )?;
 
     // Validate treasury.
     let treasury = treasury_info
@@ -46,18 +72,21 @@ pub fn process_collect(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramRes
         .has_seeds(&[TREASURY], &speedway_api::ID)?
         .as_account_mut::<Treasury>(&speedway_api::ID)?;
 
-    // Validate treasury token account.
-    treasury_tokens_info
+    // Validate Garage vault.
+    garage_vault_info
         .is_writable()?
-        .as_associated_token_account(treasury_info.key, &MINT_ADDRESS)?;
+        .has_seeds(&[GARAGE_VAULT], &speedway_api::ID)?
+        .as_token_account()?
+        .assert(|t| t.mint() == MINT_ADDRESS)?
+        .assert(|t| t.owner() == *treasury_info.key)?;
 
     // Validate mint.
     mint_info.has_address(&MINT_ADDRESS)?.is_writable()?;
 
-    // Validate team token account (for whale tax team portion).
+    // Validate team token account (for team portions of base and whale tax).
     team_tokens_info
         .is_writable()?
-        .as_associated_token_account(&ADMIN_FEE_COLLECTOR, &MINT_ADDRESS)?;
+        .as_associated_token_account(&config.fee_collector, &MINT_ADDRESS)?;
 
     // Validate board (for logging).
     board_info.as_account::<Board>(&speedway_api::ID)?;
@@ -68,75 +97,112 @@ pub fn process_collect(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramRes
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
     ore_program.is_program(&speedway_api::ID)?;
 
+    // Check if signer is the garage automation executor.
+    let mut amount = amount;
+    if *signer_info.key != *authority_info.key {
+        let automation = garage_automation_info
+            .as_account_mut::<GarageAutomation>(&speedway_api::ID)?
+            .assert_mut_err(
+                |a| a.authority == *authority_info.key,
+                OreError::NotAuthorized.into(),
+            )?
+            .assert_mut_err(
+                |a| a.executor == *signer_info.key || a.executor == EXECUTOR_ADDRESS,
+                OreError::InvalidExecutor.into(),
+            )?
+            .assert_mut_err(
+                |a| a.can_collect(clock.unix_timestamp),
+                OreError::AutomationNotReady.into(),
+            )?
+            .assert_mut_err(
+                |a| a.balance >= a.fee,
+                OreError::AutomationUnderfunded.into(),
+            )?;
+
+        // Keepers always collect the full available amount.
+        amount = 0;
+
+        // Migrate the garage, with the automation balance covering the rent.
+        let rent = migrate_garage(garage_info, garage_automation_info)?;
+        let cost = automation
+            .fee
+            .checked_add(rent)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if automation.balance < cost {
+            return Err(OreError::AutomationUnderfunded.into());
+        }
+
+        // Record execution and pay executor.
+        automation.record_collect(clock.unix_timestamp);
+        automation.balance -= cost;
+        garage_automation_info.send(automation.fee, signer_info);
+    } else {
+        migrate_garage(garage_info, signer_info)?;
+    }
+
+    // Load garage.
+    let garage = garage_info
+        .as_account_mut::<Garage>(&speedway_api::ID)?
+        .assert_mut(|g| g.authority == *authority_info.key)?;
+
     // Check if garage is exhausted.
     if garage.is_exhausted() {
         return Err(OreError::GarageExhausted.into());
     }
 
     // Calculate available rewards.
-    let available = garage.calculate_available(clock.unix_timestamp);
+    let available =
+        garage.calculate_available(clock.unix_timestamp, config.params.daily_rate_bps);
     if available == 0 {
         return Err(OreError::NoRewardsAvailable.into());
     }
 
-    // Calculate base collect tax (10% of available rewards).
-    let base_tax = available
-        .checked_mul(COLLECT_TAX_BPS)
-        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Amount after base tax (used as base for whale tax calculation).
-    let after_base_tax = available
-        .checked_sub(base_tax)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Calculate whale tax based on user's TVL percentage.
-    // Whale tax is applied AFTER the base 10% collect tax.
-    let whale_tax_bps = calculate_whale_tax_bps(garage.total_deposited, treasury.total_garage_tvl);
-    let whale_tax = after_base_tax
-        .checked_mul(whale_tax_bps)
-        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Split whale tax: 10% to team, 90% to pool.
-    let whale_tax_team = whale_tax
-        .checked_mul(WHALE_TAX_TEAM_BPS)
-        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    let whale_tax_pool = whale_tax
-        .checked_sub(whale_tax_team)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Determine amount to withdraw (0 = all available). The remainder keeps accruing.
+    let amount = if amount == 0 { available } else { amount };
+    if amount > available {
+        return Err(OreError::AmountExceedsAvailable.into());
+    }
 
-    // Net amount to transfer to user (after both taxes).
-    let net_amount = after_base_tax
-        .checked_sub(whale_tax)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Apply base collect tax, then whale tax based on user's share of outstanding obligation.
+    treasury.track_garage(garage)?;
+    let outstanding_before = garage.outstanding();
+    let CollectTax {
+        base_tax,
+        base_tax_team,
+        base_tax_pool,
+        whale_tax,
+        whale_tax_team,
+        whale_tax_pool,
+        net_amount,
+    } = apply_collect_tax(
+        amount,
+        outstanding_before,
+        treasury.total_garage_outstanding,
+        &config.params,
+    )?;
 
     // Update garage.
     garage.total_claimed = garage
         .total_claimed
-        .checked_add(available)
+        .checked_add(amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
-    garage.last_action_at = clock.unix_timestamp;
+    garage.settle(
+        clock.unix_timestamp,
+        available - amount,
+        config.params.daily_rate_bps,
+    );
 
     // Check if now exhausted.
     let is_exhausted = garage.is_exhausted();
 
-    // Add base tax + whale tax pool portion to treasury garage pool.
-    let total_pool_fee = base_tax
-        .checked_add(whale_tax_pool)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    treasury.garage_pool = treasury
-        .garage_pool
-        .checked_add(total_pool_fee)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Release the claimed obligation (exhausted garages drop out entirely).
+    treasury.update_garage_outstanding(outstanding_before, garage.outstanding())?;
 
     // Create recipient token account if needed.
     if recipient_info.data_is_empty() {
         create_associated_token_account(
             signer_info,
-            signer_info,
+            authority_info,
             recipient_info,
             mint_info,
             system_program,
@@ -146,71 +212,33 @@ pub fn process_collect(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramRes
     } else {
         recipient_info
             .is_writable()?
-            .as_associated_token_account(signer_info.key, &MINT_ADDRESS)?;
+            .as_associated_token_account(authority_info.key, &MINT_ADDRESS)?;
     }
 
-    // Transfer FUEL from treasury to user.
-    // First try from treasury token account, then mint if needed.
-    let treasury_tokens = treasury_tokens_info.as_token_account()?;
-    if treasury_tokens.amount() >= net_amount {
-        // Transfer from treasury token account.
-        transfer_signed(
-            treasury_info,
-            treasury_tokens_info,
-            recipient_info,
-            token_program,
-            net_amount,
-            &[TREASURY],
-        )?;
-        sol_log(&format!(
-            "💸 Transferred {} FUEL from treasury pool",
-            amount_to_ui_amount(net_amount, TOKEN_DECIMALS)
-        ));
-    } else {
-        // Insufficient pool - mint new FUEL as backstop.
-        // This should be rare and indicates high demand.
-        let from_pool = treasury_tokens.amount();
-        let to_mint = net_amount
-            .checked_sub(from_pool)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        // Transfer available from pool.
-        if from_pool > 0 {
-            transfer_signed(
-                treasury_info,
-                treasury_tokens_info,
-                recipient_info,
-                token_program,
-                from_pool,
-                &[TREASURY],
-            )?;
-        }
-
-        // Mint the remainder.
-        mint_to_signed(
-            mint_info,
-            recipient_info,
-            treasury_info,
-            token_program,
-            to_mint,
-            &[TREASURY],
-        )?;
-
-        sol_log(&format!(
-            "⚠️ Minted {} FUEL as backstop (pool had {})",
-            amount_to_ui_amount(to_mint, TOKEN_DECIMALS),
-            amount_to_ui_amount(from_pool, TOKEN_DECIMALS),
-        ));
-    }
+    // Pay FUEL to user from the Garage vault first, then mint the shortfall as a backstop.
+    // The backstop is capped by config; once the cap is reached, Collect fails.
+    let to_mint = pay_from_vault_or_backstop(
+        net_amount,
+        config,
+        treasury,
+        treasury_info,
+        garage_vault_info,
+        recipient_info,
+        mint_info,
+        token_program,
+    )?;
 
-    // Mint whale tax team portion to team token account (if any whale tax).
-    if whale_tax_team > 0 {
+    // Mint team portions of base tax and whale tax to team token account.
+    let total_team_fee = base_tax_team
+        .checked_add(whale_tax_team)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if total_team_fee > 0 {
         mint_to_signed(
             mint_info,
             team_tokens_info,
             treasury_info,
             token_program,
-            whale_tax_team,
+            total_team_fee,
             &[TREASURY],
         )?;
     }
@@ -220,13 +248,16 @@ pub fn process_collect(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramRes
         &[board_info.clone(), ore_program.clone()],
         CollectEvent {
             disc: OreEvent::Collect as u64,
-            authority: *signer_info.key,
-            gross_amount: available,
+            authority: *authority_info.key,
+            gross_amount: amount,
             net_amount,
             base_tax,
+            base_tax_team,
+            base_tax_pool,
             whale_tax,
             whale_tax_team,
             whale_tax_pool,
+            backstop_minted: to_mint,
             new_total_claimed: garage.total_claimed,
             is_exhausted: if is_exhausted { 1 } else { 0 },
             _padding: [0; 7],
@@ -237,7 +268,7 @@ pub fn process_collect(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramRes
 
     sol_log(&format!(
         "💰 Collect: {} FUEL withdrawn (net: {}, base_tax: {}, whale_tax: {})",
-        amount_to_ui_amount(available, TOKEN_DECIMALS),
+        amount_to_ui_amount(amount, TOKEN_DECIMALS),
         amount_to_ui_amount(net_amount, TOKEN_DECIMALS),
         amount_to_ui_amount(base_tax, TOKEN_DECIMALS),
         amount_to_ui_amount(whale_tax, TOKEN_DECIMALS),
@@ -249,3 +280,134 @@ pub fn process_collect(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramRes
 
     Ok(())
 }
+
+/// The split of a withdrawal under the base collect tax and the whale tax.
+pub struct CollectTax {
+    pub base_tax: u64,
+    pub base_tax_team: u64,
+    pub base_tax_pool: u64,
+    pub whale_tax: u64,
+    pub whale_tax_team: u64,
+    pub whale_tax_pool: u64,
+    pub net_amount: u64,
+}
+
+/// Applies the base collect tax to `amount`, then the whale tax to what remains.
+///
+/// The whale tax rate is set by `outstanding`, the user's obligation before the withdrawal,
+/// as a share of `total_outstanding`, the obligation across all tracked Garages.
+pub fn apply_collect_tax(
+    amount: u64,
+    outstanding: u64,
+    total_outstanding: u64,
+    params: &ProtocolParams,
+) -> Result<CollectTax, ProgramError> {
+    // Base collect tax: 2% of gross to team, remainder (8%) to pool.
+    let base_tax = amount
+        .checked_mul(params.collect_tax_bps)
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let base_tax_team = amount
+        .checked_mul(COLLECT_TEAM_BPS)
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let base_tax_pool = base_tax
+        .checked_sub(base_tax_team)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Amount after base tax (used as base for whale tax calculation).
+    let after_base_tax = amount
+        .checked_sub(base_tax)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Whale tax: 30% to team, 70% to pool.
+    let whale_tax_bps = params.whale_tax_bps(outstanding, total_outstanding);
+    let whale_tax = after_base_tax
+        .checked_mul(whale_tax_bps)
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let whale_tax_team = whale_tax
+        .checked_mul(WHALE_TAX_TEAM_BPS)
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let whale_tax_pool = whale_tax
+        .checked_sub(whale_tax_team)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Net amount to transfer to user (after both taxes).
+    let net_amount = after_base_tax
+        .checked_sub(whale_tax)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(CollectTax {
+        base_tax,
+        base_tax_team,
+        base_tax_pool,
+        whale_tax,
+        whale_tax_team,
+        whale_tax_pool,
+        net_amount,
+    })
+}
+
+/// Pays `amount` FUEL to `recipient_info` from the Garage vault, minting any shortfall.
+///
+/// The mint counts against the Garage backstop cap in Config and fails with
+/// InsufficientPoolBalance once the cap would be exceeded. Returns the amount minted.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_from_vault_or_backstop<'a>(
+    amount: u64,
+    config: &Config,
+    treasury: &mut Treasury,
+    treasury_info: &AccountInfo<'a>,
+    garage_vault_info: &AccountInfo<'a>,
+    recipient_info: &AccountInfo<'a>,
+    mint_info: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+) -> Result<u64, ProgramError> {
+    let vault_balance = garage_vault_info.as_token_account()?.amount();
+    let from_vault = amount.min(vault_balance);
+    let to_mint = amount
+        .checked_sub(from_vault)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    if from_vault > 0 {
+        transfer_signed(
+            treasury_info,
+            garage_vault_info,
+            recipient_info,
+            token_program,
+            from_vault,
+            &[TREASURY],
+        )?;
+        treasury.garage_pool = treasury.garage_pool.saturating_sub(from_vault);
+    }
+
+    if to_mint > 0 {
+        let backstop_minted = treasury
+            .garage_backstop_minted
+            .checked_add(to_mint)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if backstop_minted > config.garage_backstop_cap {
+            return Err(OreError::InsufficientPoolBalance.into());
+        }
+        treasury.garage_backstop_minted = backstop_minted;
+
+        mint_to_signed(
+            mint_info,
+            recipient_info,
+            treasury_info,
+            token_program,
+            to_mint,
+            &[TREASURY],
+        )?;
+
+        sol_log(&format!(
+            "⚠️ Minted {} FUEL as backstop (vault had {})",
+            amount_to_ui_amount(to_mint, TOKEN_DECIMALS),
+            amount_to_ui_amount(from_vault, TOKEN_DECIMALS),
+        ));
+    }
+
+    Ok(to_mint)
+}
@@ -3,19 +3,51 @@ use speedway_api::prelude::*;
 use solana_program::{keccak::hashv, log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::automate::migrate_automation_from_balance;
+
+/// The squares and amounts requested by a deploy instruction.
+enum Deployment {
+    /// Deploy: the same amount to every square in the mask.
+    Uniform { amount: u64, mask: u32 },
+
+    /// DeployMulti: an explicit amount per square.
+    PerSquare([u64; 25]),
+}
+
 /// Deploys capital to prospect on a square.
 pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = Deploy::try_from_bytes(data)?;
-    let mut amount = u64::from_le_bytes(args.amount);
+    let amount = u64::from_le_bytes(args.amount);
     let mask = u32::from_le_bytes(args.squares);
 
+    deploy(accounts, args.table_id, Deployment::Uniform { amount, mask })
+}
+
+/// DeployMulti: Deploys a different amount to each listed square in one instruction.
+///
+/// Squares are recorded exactly as Deploy records them. Weighted deploys are manual only,
+/// so an authority with an active automation must close it first.
+pub fn process_deploy_multi(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let header_size = std::mem::size_of::<DeployMulti>();
+    if data.len() < header_size {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (header, entries) = data.split_at(header_size);
+    let args = DeployMulti::try_from_bytes(header)?;
+    let amounts = DeployMulti::amounts(entries)?;
+
+    deploy(accounts, args.table_id, Deployment::PerSquare(amounts))
+}
+
+fn deploy(accounts: &[AccountInfo<'_>], table_id: u8, deployment: Deployment) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
     let (ore_accounts, entropy_accounts) = accounts.split_at(9);
     sol_log(&format!("Ore accounts: {:?}", ore_accounts.len()).to_string());
     sol_log(&format!("Entropy accounts: {:?}", entropy_accounts.len()).to_string());
-    let [signer_info, authority_info, automation_info, board_info, _config_info, miner_info, round_info, system_program, ore_program] =
+    let [signer_info, authority_info, automation_info, board_info, config_info, miner_info, round_info, system_program, ore_program] =
         ore_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -26,28 +58,46 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         .is_writable()?
         .has_seeds(&[AUTOMATION, &authority_info.key.to_bytes()], &speedway_api::ID)?;
     let board = board_info
+        .has_seeds(&[BOARD, table_seed(&table_id)], &speedway_api::ID)?
         .as_account_mut::<Board>(&speedway_api::ID)?
         .assert_mut(|b| clock.slot >= b.start_slot && clock.slot < b.end_slot)?;
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(|c| !c.is_paused(PAUSE_DEPLOY), OreError::Paused.into())?;
+    round_info.is_writable()?.has_seeds(
+        &[ROUND, table_seed(&table_id), &board.round_id.to_le_bytes()],
+        &speedway_api::ID,
+    )?;
+    migrate_round(
+        round_info,
+        signer_info,
+        board.intermission_slots(&config.params),
+        config.params.checkpoint_window_slots(),
+    )?;
     let round = round_info
         .as_account_mut::<Round>(&speedway_api::ID)?
         .assert_mut(|r| r.id == board.round_id)?;
-    miner_info
-        .is_writable()?
-        .has_seeds(&[MINER, &authority_info.key.to_bytes()], &speedway_api::ID)?;
+    miner_info.is_writable()?.has_seeds(
+        &[MINER, &authority_info.key.to_bytes(), table_seed(&table_id)],
+        &speedway_api::ID,
+    )?;
     system_program.is_program(&system_program::ID)?;
 
     // Wait until first deploy to start round.
     if board.end_slot == u64::MAX {
         board.start_slot = clock.slot;
-        board.end_slot = board.start_slot + 150;
-        round.expires_at = board.end_slot + ONE_DAY_SLOTS;
+        board.end_slot = board.start_slot + board.round_slots(&config.params);
+        round.expires_at = board.end_slot + config.params.claim_expiry_slots;
+        round.intermission_slots = board.intermission_slots(&config.params);
+        round.checkpoint_window_slots = config.params.checkpoint_window_slots();
 
         // Bump var to the next value.
         let [var_info, entropy_program] = entropy_accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
         var_info
-            .has_address(&VAR_ADDRESS)?
+            .has_address(&board.var)?
             .as_account::<Var>(&entropy_api::ID)?
             .assert(|v| v.authority == *board_info.key)?;
         entropy_program.is_program(&entropy_api::ID)?;
@@ -57,13 +107,14 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             &entropy_api::sdk::next(*board_info.key, *var_info.key, board.end_slot),
             &[board_info.clone(), var_info.clone()],
             &entropy_api::ID,
-            &[BOARD],
+            &[BOARD, table_seed(&table_id)],
         )?;
     }
 
     // Check if signer is the automation executor.
     let mut strategy = u64::MAX;
-    let automation = if !automation_info.data_is_empty() {
+    let mut automation = if !automation_info.data_is_empty() {
+        migrate_automation_from_balance(automation_info)?;
         let automation = automation_info
             .as_account_mut::<Automation>(&speedway_api::ID)?
             .assert_mut(|a| a.executor == *signer_info.key || a.executor == EXECUTOR_ADDRESS)?
@@ -74,52 +125,44 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         None
     };
 
-    // Update amount and mask for automation.
-    let mut squares = [false; 25];
+    // Close the automation and refund its balance if it has hit a lifetime limit.
     if let Some(automation) = &automation {
-        // Set amount and squares based on automation strategy.
-        match AutomationStrategy::from_u64(automation.strategy as u64) {
-            AutomationStrategy::Preferred => {
-                // Preferred automation strategy. Use the miner authority's provided mask.
-                amount = automation.amount;
-                for i in 0..25 {
-                    squares[i] = (automation.mask & (1 << i)) != 0;
-                }
-            }
-            AutomationStrategy::Random => {
-                // Random automation strategy. Generate a random mask based on number of squares user wants to deploy to.
-                amount = automation.amount;
-                let num_squares = ((automation.mask & 0xFF) as u64).min(25);
-                let r = hashv(&[&automation.authority.to_bytes(), &round.id.to_le_bytes()]).0;
-                squares = generate_random_mask(num_squares, &r);
-            }
-            AutomationStrategy::Discretionary => {
-                // Discretionary automation strategy. Use the executor's provided mask.
-                amount = amount.min(automation.amount);
-                for i in 0..25 {
-                    squares[i] = (mask & (1 << i)) != 0;
-                }
-            }
-        }
-    } else {
-        // Convert provided 32-bit mask into array of 25 booleans, where each bit in the mask
-        // determines if that square index is selected (true) or not (false)
-        for i in 0..25 {
-            squares[i] = (mask & (1 << i)) != 0;
+        if let Some(reason) = automation.limit_hit(clock.slot) {
+            let event = AutomationStoppedEvent {
+                disc: OreEvent::AutomationStopped as u64,
+                authority: automation.authority,
+                reason: reason as u64,
+                rounds_played: automation.rounds_played,
+                refund: automation.balance,
+                round_id: round.id,
+                ts: clock.unix_timestamp,
+            };
+            automation_info.close(authority_info)?;
+            table_log(
+                table_id,
+                &[board_info.clone(), ore_program.clone()],
+                event.to_bytes(),
+            )?;
+            sol_log(&format!("Automation closed: {:?}", reason).as_str());
+            return Ok(());
         }
     }
 
     // Open miner account.
+    // Executors may open it on the authority's behalf, e.g. on a table the authority has not played yet.
     let miner = if miner_info.data_is_empty() {
+        if automation.is_none() && authority_info.key != signer_info.key {
+            return Err(OreError::NotAuthorized.into());
+        }
         create_program_account::<Miner>(
             miner_info,
             system_program,
             signer_info,
             &speedway_api::ID,
-            &[MINER, &signer_info.key.to_bytes()],
+            &[MINER, &authority_info.key.to_bytes(), table_seed(&table_id)],
         )?;
         let miner = miner_info.as_account_mut::<Miner>(&speedway_api::ID)?;
-        miner.authority = *signer_info.key;
+        miner.authority = *authority_info.key;
         miner.deployed = [0; 25];
         miner.cumulative = [0; 25];
         miner.rewards_sol = 0;
@@ -155,21 +198,118 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         miner.round_id = round.id;
     }
 
+    // Resolve the amount to deploy to each square.
+    let day = (clock.unix_timestamp / ONE_DAY) as u64;
+    let mut amount = 0;
+    let mut amounts = [0u64; 25];
+    match deployment {
+        Deployment::Uniform {
+            amount: requested,
+            mask,
+        } => {
+            // Update amount and mask for automation.
+            let mut squares = [false; 25];
+            amount = requested;
+            if let Some(automation) = automation.as_mut() {
+                // Credit any SOL won since the last deploy.
+                automation.record_rewards(table_id, miner.lifetime_rewards_sol);
+
+                // Set amount and squares based on automation strategy.
+                match AutomationStrategy::from_u64(automation.strategy as u64) {
+                    AutomationStrategy::Preferred => {
+                        // Preferred automation strategy. Use the miner authority's provided mask.
+                        amount = automation.amount;
+                        for i in 0..25 {
+                            squares[i] = (automation.mask & (1 << i)) != 0;
+                        }
+                    }
+                    AutomationStrategy::Random => {
+                        // Random automation strategy. Generate a random mask based on number of squares user wants to deploy to.
+                        amount = automation.amount;
+                        let num_squares = ((automation.mask & 0xFF) as u64).min(25);
+                        let r = hashv(&[&automation.authority.to_bytes(), &round.id.to_le_bytes()]).0;
+                        squares = generate_random_mask(num_squares, &r);
+                    }
+                    AutomationStrategy::Discretionary => {
+                        // Discretionary automation strategy. Use the executor's provided mask.
+                        amount = amount.min(automation.amount);
+                        for i in 0..25 {
+                            squares[i] = (mask & (1 << i)) != 0;
+                        }
+                    }
+                    AutomationStrategy::Contrarian => {
+                        // Contrarian automation strategy. Deploy to the least-deployed squares of the round.
+                        amount = automation.amount;
+                        let num_squares = ((automation.mask & 0xFF) as u64).min(25);
+                        squares = Automation::contrarian_squares(num_squares, &round.deployed);
+                    }
+                    AutomationStrategy::Budget => {
+                        // Budget automation strategy. Use the preferred mask, trimmed to the remaining budget.
+                        amount = automation.amount;
+                        squares = automation.budget_squares(round.id, day);
+                    }
+                    AutomationStrategy::StopLoss => {
+                        // Stop-loss automation strategy. Use the preferred mask until losses exceed the threshold.
+                        amount = automation.amount;
+                        if automation.stopped == 0 && automation.is_stop_loss_hit() {
+                            automation.stopped = 1;
+                            sol_log(
+                                &format!(
+                                    "Automation stopped: {} SOL lost",
+                                    lamports_to_sol(automation.losses())
+                                )
+                                .as_str(),
+                            );
+                        }
+                        if automation.stopped == 0 {
+                            squares = automation.preferred_squares();
+                        }
+                    }
+                }
+            } else {
+                // Convert provided 32-bit mask into array of 25 booleans, where each bit in the mask
+                // determines if that square index is selected (true) or not (false)
+                for i in 0..25 {
+                    squares[i] = (mask & (1 << i)) != 0;
+                }
+            }
+
+            // Enforce the table minimum.
+            if amount < board.min_deploy {
+                return Err(OreError::AmountTooSmall.into());
+            }
+
+            for i in 0..25 {
+                if squares[i] {
+                    amounts[i] = amount;
+                }
+            }
+        }
+        Deployment::PerSquare(requested) => {
+            // Weighted deploys are manual only.
+            if automation.is_some() {
+                return Err(OreError::NotAuthorized.into());
+            }
+
+            // Enforce the table minimum on every square.
+            if requested.iter().any(|&a| a > 0 && a < board.min_deploy) {
+                return Err(OreError::AmountTooSmall.into());
+            }
+
+            amounts = requested;
+        }
+    }
+
     // Update total miners for round.
     let is_first_deploy = miner.deployed.iter().sum::<u64>() == 0;
 
     // Calculate all deployments.
     let mut total_amount = 0;
     let mut total_squares = 0;
-    let mut deployed_squares = [false; 25];
-    for (square_id, &should_deploy) in squares.iter().enumerate() {
-        // Skip if square index is out of bounds.
-        if square_id > 24 {
-            break;
-        }
-
+    let mut deployed_amounts = [0u64; 25];
+    for (square_id, &square_amount) in amounts.iter().enumerate() {
         // Skip if square is not deployed to.
-        if !should_deploy {
+        if square_amount == 0 {
             continue;
         }
 
@@ -182,17 +322,17 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         miner.cumulative[square_id] = round.deployed[square_id];
 
         // Update miner
-        miner.deployed[square_id] = amount;
+        miner.deployed[square_id] = square_amount;
 
         // Update board
-        round.deployed[square_id] += amount;
-        round.total_deployed += amount;
+        round.deployed[square_id] += square_amount;
+        round.total_deployed += square_amount;
         round.count[square_id] += 1;
 
         // Update totals.
-        total_amount += amount;
+        total_amount += square_amount;
         total_squares += 1;
-        deployed_squares[square_id] = true;
+        deployed_amounts[square_id] = square_amount;
 
         // Exit early if automation does not have enough balance for another square.
         if let Some(automation) = &automation {
@@ -212,11 +352,12 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
 
     // Top up checkpoint fee.
     if miner.checkpoint_fee == 0 {
-        miner.checkpoint_fee = CHECKPOINT_FEE;
-        miner_info.collect(CHECKPOINT_FEE, &signer_info)?;
+        miner.checkpoint_fee = config.params.checkpoint_fee;
+        miner_info.collect(config.params.checkpoint_fee, &signer_info)?;
     }
 
     // Transfer SOL.
+    let mut stopped = None;
     if let Some(automation) = automation {
         // Calculate automation fee.
         let automation_fee = if is_first_deploy && total_amount > 0 {
@@ -227,11 +368,31 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
 
         // Update automation balance.
         automation.balance -= total_amount + automation_fee;
+        automation.record_spend(round.id, day, total_amount);
         automation_info.send(total_amount, &round_info);
         automation_info.send(automation_fee, &signer_info);
 
-        // Close automation if balance is less than what's required to deploy 1 square.
-        if automation.balance < automation.amount + automation.fee {
+        // Count rounds played.
+        if is_first_deploy && total_amount > 0 {
+            automation.rounds_played += 1;
+        }
+
+        // Close automation if it has hit a lifetime limit or its balance is less than what's
+        // required to deploy 1 square. The remaining balance is refunded to the authority.
+        let reason = automation.limit_hit(clock.slot).or_else(|| {
+            (automation.balance < automation.amount + automation.fee)
+                .then_some(AutomationStopReason::Balance)
+        });
+        if let Some(reason) = reason {
+            stopped = Some(AutomationStoppedEvent {
+                disc: OreEvent::AutomationStopped as u64,
+                authority: automation.authority,
+                reason: reason as u64,
+                rounds_played: automation.rounds_played,
+                refund: automation.balance,
+                round_id: round.id,
+                ts: clock.unix_timestamp,
+            });
             automation_info.close(authority_info)?;
         }
     } else {
@@ -240,14 +401,15 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
 
     // Rebuild the mask from the deployed squares.
     let mut deployed_mask = 0;
-    for (square_id, &deployed) in deployed_squares.iter().enumerate() {
-        if deployed {
+    for (square_id, &deployed) in deployed_amounts.iter().enumerate() {
+        if deployed > 0 {
             deployed_mask |= 1 << square_id;
         }
     }
 
     // Log the deploy event.
-    program_log(
+    table_log(
+        table_id,
         &[board_info.clone(), ore_program.clone()],
         DeployEvent {
             disc: 2,
@@ -258,17 +420,28 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             signer: *signer_info.key,
             strategy,
             total_squares,
+            total_amount,
+            amounts: deployed_amounts,
             ts: clock.unix_timestamp,
         }
         .to_bytes(),
     )?;
 
+    // Log the automation stop event.
+    if let Some(event) = stopped {
+        table_log(
+            table_id,
+            &[board_info.clone(), ore_program.clone()],
+            event.to_bytes(),
+        )?;
+    }
+
     // Log
     sol_log(
         &format!(
             "Round #{}: deploying {} SOL to {} squares",
             round.id,
-            lamports_to_sol(amount),
+            lamports_to_sol(total_amount),
             total_squares,
         )
         .as_str(),
@@ -293,3 +466,27 @@ fn generate_random_mask(num_squares: u64, r: &[u8]) -> [bool; 25] {
     }
     new_mask
 }
+
+/// Migrates a Round to the current layout, with `payer_info` covering the rent.
+///
+/// Rounds opened before the timing snapshot are given the values passed in, normally the
+/// live ones, since what they started with was never recorded.
+pub fn migrate_round(
+    round_info: &AccountInfo<'_>,
+    payer_info: &AccountInfo<'_>,
+    intermission_slots: u64,
+    checkpoint_window_slots: u64,
+) -> ProgramResult {
+    let round_len = 8 + std::mem::size_of::<Round>();
+    if round_info.data_is_empty() || round_info.data_len() >= round_len {
+        return Ok(());
+    }
+    let required = Rent::get()?.minimum_balance(round_len);
+    let current = Rent::get()?.minimum_balance(round_info.data_len());
+    round_info.collect(required.saturating_sub(current), payer_info)?;
+    round_info.realloc(round_len, true)?;
+    let round = round_info.as_account_mut::<Round>(&speedway_api::ID)?;
+    round.intermission_slots = intermission_slots;
+    round.checkpoint_window_slots = checkpoint_window_slots;
+    Ok(())
+}
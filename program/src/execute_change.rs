@@ -0,0 +1,98 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Applies a pending change to Config, or to the Board of its table, once its timelock has
+/// elapsed.
+///
+/// Callable by anyone. The account rent is returned to the proposer. Table changes require
+/// the Board to be on the current layout (see MigrateBoard).
+pub fn process_execute_change(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, pending_change_info, proposer_info, board_info, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account_mut::<Config>(&speedway_api::ID)?;
+    let pending_change = *pending_change_info
+        .is_writable()?
+        .as_account::<PendingChange>(&speedway_api::ID)?
+        .assert_err(|p| p.is_executable(), OreError::InvalidParams.into())?
+        .assert_err(
+            |p| p.is_ready(clock.unix_timestamp),
+            OreError::TimelockNotElapsed.into(),
+        )?;
+    pending_change_info.has_seeds(
+        &[PENDING_CHANGE, &pending_change.id.to_le_bytes()],
+        &speedway_api::ID,
+    )?;
+    proposer_info
+        .is_writable()?
+        .has_address(&pending_change.proposer)?;
+    ore_program.is_program(&speedway_api::ID)?;
+
+    // Re-validate, since bounds may have changed since the proposal.
+    if !pending_change.is_valid() {
+        return Err(OreError::InvalidParams.into());
+    }
+
+    // Table changes go to the board of their table; the rest log to the default table.
+    let is_table_change = pending_change.kind == ChangeKind::Table as u64;
+    let table_id = if is_table_change {
+        pending_change.value as u8
+    } else {
+        DEFAULT_TABLE_ID
+    };
+    board_info
+        .has_seeds(&[BOARD, table_seed(&table_id)], &speedway_api::ID)?
+        .as_account::<Board>(&speedway_api::ID)?;
+
+    // Apply change.
+    pending_change.apply(config);
+    if is_table_change {
+        let board = board_info
+            .is_writable()?
+            .as_account_mut::<Board>(&speedway_api::ID)?;
+        pending_change.apply_table(board);
+    }
+
+    // Close pending change.
+    pending_change_info.close(proposer_info)?;
+
+    // Emit events.
+    if is_table_change {
+        table_log(
+            table_id,
+            &[board_info.clone(), ore_program.clone()],
+            TableUpdatedEvent {
+                disc: OreEvent::TableUpdated as u64,
+                table_id: table_id as u64,
+                authority: pending_change.proposer,
+                min_deploy: pending_change.min_deploy,
+                round_slots: pending_change.round_slots,
+                intermission_slots: pending_change.intermission_slots,
+                ts: clock.unix_timestamp,
+            }
+            .to_bytes(),
+        )?;
+    }
+    table_log(
+        table_id,
+        &[board_info.clone(), ore_program.clone()],
+        ChangeExecutedEvent {
+            disc: OreEvent::ChangeExecuted as u64,
+            id: pending_change.id,
+            kind: pending_change.kind,
+            executor: *signer_info.key,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
@@ -5,23 +5,65 @@ use steel::*;
 
 /// FuelUp: Deposit FUEL tokens into the Garage faucet system.
 ///
-/// Fee breakdown (10% total):
-/// - 2% → Team fee collector
-/// - 5% → Referrer (credited to referrer's Garage)
-/// - 3% → Pool (goes to Treasury garage_pool)
+/// Deposit split (100% of deposit):
+/// - 55% → Burned permanently
+/// - 28% → Pool (transferred to the Garage vault, tracked in garage_pool)
+/// - 10% → Referrers (credited up the referrer chain, 6% / 3% / 1%, tokens burned)
+///   A level whose Garage is exhausted forfeits its share to the pool. A transferred Garage
+///   is followed to its new owner through its GarageForward record. A closed Garage without
+///   a forward ends the chain, sending its share and every level above it to the pool.
+/// - 7% → Team (transferred to the fee collector's FUEL token account)
 ///
-/// The remaining 90% is credited to the user's Garage as total_deposited.
-/// Tokens are burned (not transferred) - principal is never withdrawable.
+/// The full deposit is credited to the user's Garage as total_deposited.
+/// Principal is never withdrawable.
+/// Depositing into an exhausted Garage starts a new cycle (lifetime totals archived).
 ///
 /// First deposit requires a valid referrer who has an existing Garage account.
+/// Users without a referrer may use the house Garage (HOUSE_ADDRESS). Shares for the house
+/// level and every level above it are added to the team fee.
+///
+/// Repeat deposits always credit the Garage's stored referrer; the referrer Garage account
+/// passed must be that referrer's Garage PDA. Garages for levels 2 and up are passed as
+/// remaining accounts, each being the Garage PDA of the previous level's stored referrer.
+/// Whenever a level's Garage is closed, the next remaining accounts are its GarageForward PDA
+/// and, if that exists, the recipient's Garage PDA.
+/// Each deposit is recorded in the user's ReferralLedger, which is created on first use.
 pub fn process_fuel_up(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = FuelUp::try_from_bytes(data)?;
     let amount = u64::from_le_bytes(args.amount);
 
+    // The referrer is passed directly.
+    let Some(referrer_info) = accounts.get(5) else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    fuel_up(accounts, amount, *referrer_info.key)
+}
+
+/// FuelUpWithCode: FuelUp with the referrer resolved from a ReferralCode account.
+///
+/// The referral code account takes the place of the referrer account. Its authority is
+/// used as the referrer, and the referrer Garage must belong to that authority.
+pub fn process_fuel_up_with_code(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = FuelUpWithCode::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Resolve referrer from the referral code.
+    let Some(referral_code_info) = accounts.get(5) else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let referral_code = referral_code_info.as_account::<ReferralCode>(&speedway_api::ID)?;
+    referral_code_info.has_seeds(&[REFERRAL_CODE, &referral_code.code], &speedway_api::ID)?;
+
+    fuel_up(accounts, amount, referral_code.authority)
+}
+
+fn fuel_up(accounts: &[AccountInfo<'_>], amount: u64, referrer: Pubkey) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, payer_info, mint_info, sender_info, garage_info, referrer_info, referrer_garage_info, treasury_info, team_fee_collector_info, board_info, system_program, token_program, ore_program] =
+    let [signer_info, payer_info, mint_info, sender_info, garage_info, _referrer_info, referrer_garage_info, referral_ledger_info, treasury_info, config_info, garage_vault_info, team_tokens_info, board_info, system_program, token_program, ore_program, upline_infos @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -43,21 +85,41 @@ pub fn process_fuel_up(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     garage_info
         .is_writable()?
         .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    migrate_garage(garage_info, payer_info)?;
 
-    // Validate referrer is not self.
-    if referrer_info.key == signer_info.key {
-        return Err(OreError::InvalidReferrer.into());
-    }
+    // Validate referral ledger PDA.
+    referral_ledger_info
+        .is_writable()?
+        .has_seeds(&[REFERRAL_LEDGER, &signer_info.key.to_bytes()], &speedway_api::ID)?;
 
     // Validate treasury.
     let treasury = treasury_info
         .is_writable()?
+        .has_seeds(&[TREASURY], &speedway_api::ID)?
         .as_account_mut::<Treasury>(&speedway_api::ID)?;
 
-    // Validate team fee collector.
-    team_fee_collector_info
+    // Validate config.
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(|c| !c.is_paused(PAUSE_GARAGE_DEPOSIT), OreError::Paused.into())?
+        .assert_err(
+            |c| c.fee_collector != Pubkey::default(),
+            OreError::RoleNotSet.into(),
+        )?;
+
+    // Validate Garage vault (pool custody).
+    garage_vault_info
         .is_writable()?
-        .has_address(&ADMIN_FEE_COLLECTOR)?;
+        .has_seeds(&[GARAGE_VAULT], &speedway_api::ID)?
+        .as_token_account()?
+        .assert(|t| t.mint() == MINT_ADDRESS)?
+        .assert(|t| t.owner() == *treasury_info.key)?;
+
+    // Validate team token account.
+    team_tokens_info
+        .is_writable()?
+        .as_associated_token_account(&config.fee_collector, &MINT_ADDRESS)?;
 
     // Validate board (for logging).
     board_info.as_account::<Board>(&speedway_api::ID)?;
@@ -78,42 +140,45 @@ pub fn process_fuel_up(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     }
 
     // Calculate fees using checked arithmetic.
-    let team_fee = amount
+    // Referral shares are settled per level once the referrer chain is walked.
+    let mut team_fee = amount
         .checked_mul(FUEL_UP_TEAM_BPS)
         .and_then(|v| v.checked_div(DENOMINATOR_BPS))
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    let ref_fee = amount
-        .checked_mul(FUEL_UP_REF_BPS)
-        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    let pool_fee = amount
+    let mut pool_fee = amount
         .checked_mul(FUEL_UP_POOL_BPS)
         .and_then(|v| v.checked_div(DENOMINATOR_BPS))
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    let total_tax = team_fee
-        .checked_add(ref_fee)
-        .and_then(|v| v.checked_add(pool_fee))
+    let burn_amount = amount
+        .checked_mul(FUEL_UP_BURN_BPS)
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    let net_amount = amount
-        .checked_sub(total_tax)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Yields accrue on the full deposit.
+    let net_amount = amount;
 
     // Open or update garage account.
-    let garage = if garage_info.data_is_empty() {
+    let is_new = garage_info.data_is_empty();
+    let garage = if is_new {
         // First deposit - referrer is mandatory.
+        // Validate referrer is not self.
+        if referrer == *signer_info.key {
+            return Err(OreError::InvalidReferrer.into());
+        }
+
         // Validate referrer has an existing Garage account.
         if referrer_garage_info.data_is_empty() {
             return Err(OreError::ReferrerNoGarage.into());
         }
         referrer_garage_info
             .is_writable()?
-            .has_seeds(&[GARAGE, &referrer_info.key.to_bytes()], &speedway_api::ID)?
+            .has_seeds(&[GARAGE, &referrer.to_bytes()], &speedway_api::ID)?;
+        migrate_garage(referrer_garage_info, payer_info)?;
+        referrer_garage_info
             .as_account_mut::<Garage>(&speedway_api::ID)?
-            .assert_mut(|g| g.authority == *referrer_info.key)?;
+            .assert_mut(|g| g.authority == referrer)?;
 
         // Create new Garage account.
         create_program_account::<Garage>(
@@ -126,22 +191,20 @@ pub fn process_fuel_up(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
         let garage = garage_info.as_account_mut::<Garage>(&speedway_api::ID)?;
         garage.authority = *signer_info.key;
-        garage.referrer = *referrer_info.key;
+        garage.referrer = referrer;
         garage.total_deposited = 0;
         garage.total_claimed = 0;
         garage.max_payout = 0;
         garage.last_action_at = clock.unix_timestamp;
         garage.created_at = clock.unix_timestamp;
         garage.direct_referrals = 0;
-        garage._padding = 0;
+        garage.outstanding_tracked = 0;
         garage.lifetime_ref_earnings = 0;
-
-        // Increment referrer's direct_referrals count.
-        let referrer_garage = referrer_garage_info.as_account_mut::<Garage>(&speedway_api::ID)?;
-        referrer_garage.direct_referrals = referrer_garage
-            .direct_referrals
-            .checked_add(1)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
+        garage.cycle = 0;
+        garage.lifetime_deposited = 0;
+        garage.lifetime_claimed = 0;
+        garage.level_referrals = [0; REFERRAL_LEVELS];
+        garage.level_ref_earnings = [0; REFERRAL_LEVELS];
 
         garage
     } else {
@@ -151,67 +214,243 @@ pub fn process_fuel_up(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             .assert_mut(|g| g.authority == *signer_info.key)?
     };
 
-    // Check if garage is exhausted.
+    // Exhausted garages start a new cycle on deposit.
     if garage.is_exhausted() {
-        return Err(OreError::GarageExhausted.into());
+        let event = garage.start_new_cycle(clock.unix_timestamp);
+        emit_new_cycle(board_info, ore_program, event)?;
     }
 
     // Update garage deposits.
+    treasury.track_garage(garage)?;
+    let outstanding_before = garage.outstanding();
     garage.total_deposited = garage
         .total_deposited
         .checked_add(net_amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
     garage.last_action_at = clock.unix_timestamp;
-    garage.update_max_payout();
-
-    // Credit referral fee to referrer's Garage.
-    // Only if referrer_garage_info is not empty (subsequent deposits may not have it).
-    if !referrer_garage_info.data_is_empty() {
-        let referrer_garage = referrer_garage_info.as_account_mut::<Garage>(&speedway_api::ID)?;
-        referrer_garage.total_deposited = referrer_garage
-            .total_deposited
-            .checked_add(ref_fee)
+    garage.update_max_payout(config.params.max_payout_mult);
+    treasury.update_garage_outstanding(outstanding_before, garage.outstanding())?;
+    treasury.total_garage_tvl = treasury
+        .total_garage_tvl
+        .checked_add(net_amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Pay referral shares up the referrer chain, starting from the stored referrer.
+    // Level 1 is the referrer Garage account; higher levels are passed as remaining accounts
+    // and must follow each Garage's stored referrer.
+    let mut upline_infos = upline_infos.iter();
+    let mut ref_fee = 0u64;
+    let mut ref_redirected = 0u64;
+    let mut level_referrer = garage.referrer;
+    let mut house_reached = false;
+    let mut chain_broken = false;
+    for (level, level_bps) in config.params.ref_level_bps.iter().enumerate() {
+        let share = amount
+            .checked_mul(*level_bps)
+            .and_then(|v| v.checked_div(DENOMINATOR_BPS))
             .ok_or(ProgramError::ArithmeticOverflow)?;
-        referrer_garage.lifetime_ref_earnings = referrer_garage
-            .lifetime_ref_earnings
-            .checked_add(ref_fee)
+
+        // Levels at or above the house route their share to the team.
+        house_reached |= level_referrer == Pubkey::default();
+        if house_reached {
+            team_fee = team_fee
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            continue;
+        }
+
+        // Levels above a closed Garage (or a chain looping back to the user) go to the pool.
+        chain_broken |= level_referrer == *signer_info.key;
+        let level_garage_info = if chain_broken {
+            None
+        } else {
+            let info = match level {
+                0 => referrer_garage_info,
+                _ => upline_infos
+                    .next()
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?,
+            };
+            info.is_writable()?
+                .has_seeds(&[GARAGE, &level_referrer.to_bytes()], &speedway_api::ID)?;
+            resolve_garage(
+                signer_info.key,
+                &mut level_referrer,
+                info,
+                &mut upline_infos,
+            )?
+        };
+        let Some(level_garage_info) = level_garage_info else {
+            chain_broken = true;
+            ref_redirected = ref_redirected
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            continue;
+        };
+        migrate_garage(level_garage_info, payer_info)?;
+        let level_garage = level_garage_info.as_account_mut::<Garage>(&speedway_api::ID)?;
+
+        // Count the new referral at every level of the chain.
+        if is_new {
+            level_garage.level_referrals[level] = level_garage.level_referrals[level]
+                .checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            if level == 0 {
+                level_garage.direct_referrals = level_garage
+                    .direct_referrals
+                    .checked_add(1)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+            }
+        }
+
+        if level_referrer == HOUSE_ADDRESS {
+            // The house Garage never accrues referral fees.
+            house_reached = true;
+            team_fee = team_fee
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        } else if level_garage.is_exhausted() {
+            // Exhausted Garages forfeit their share to the pool; the chain continues.
+            ref_redirected = ref_redirected
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        } else if share > 0 {
+            // Credit referral share to the level's Garage.
+            treasury.track_garage(level_garage)?;
+            let level_outstanding_before = level_garage.outstanding();
+            level_garage.total_deposited = level_garage
+                .total_deposited
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            level_garage.lifetime_ref_earnings = level_garage
+                .lifetime_ref_earnings
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            level_garage.level_ref_earnings[level] = level_garage.level_ref_earnings[level]
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            level_garage.update_max_payout(config.params.max_payout_mult);
+            treasury
+                .update_garage_outstanding(level_outstanding_before, level_garage.outstanding())?;
+            treasury.total_garage_tvl = treasury
+                .total_garage_tvl
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            ref_fee = ref_fee
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+
+            program_log(
+                &[board_info.clone(), ore_program.clone()],
+                ReferralRewardEvent {
+                    disc: OreEvent::ReferralReward as u64,
+                    referee: *signer_info.key,
+                    referrer: level_referrer,
+                    level: level as u64 + 1,
+                    amount: share,
+                    ts: clock.unix_timestamp,
+                }
+                .to_bytes(),
+            )?;
+        }
+
+        level_referrer = level_garage.referrer;
+    }
+
+    // Any part of the referral fee not split across levels goes to the pool.
+    let ref_unallocated = amount
+        .checked_mul(config.params.ref_unallocated_bps())
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_fee = pool_fee
+        .checked_add(ref_unallocated)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Redirected referral shares go to the pool.
+    if ref_redirected > 0 {
+        pool_fee = pool_fee
+            .checked_add(ref_redirected)
             .ok_or(ProgramError::ArithmeticOverflow)?;
-        referrer_garage.update_max_payout();
+        sol_log(&format!(
+            "↪️ Referrer Garage inactive - {} FUEL referral share sent to pool",
+            amount_to_ui_amount(ref_redirected, TOKEN_DECIMALS)
+        ));
     }
 
+    // Record deposit in the referral ledger.
+    let referral_ledger = if referral_ledger_info.data_is_empty() {
+        create_program_account::<ReferralLedger>(
+            referral_ledger_info,
+            system_program,
+            payer_info,
+            &speedway_api::ID,
+            &[REFERRAL_LEDGER, &signer_info.key.to_bytes()],
+        )?;
+        let referral_ledger =
+            referral_ledger_info.as_account_mut::<ReferralLedger>(&speedway_api::ID)?;
+        referral_ledger.referee = *signer_info.key;
+        referral_ledger.referrer = garage.referrer;
+        referral_ledger.created_at = clock.unix_timestamp;
+        referral_ledger
+    } else {
+        referral_ledger_info
+            .as_account_mut::<ReferralLedger>(&speedway_api::ID)?
+            .assert_mut(|l| l.referee == *signer_info.key)?
+    };
+    referral_ledger.record(
+        garage.referrer,
+        amount,
+        ref_fee,
+        ref_redirected,
+        clock.unix_timestamp,
+    );
+
     // Update treasury garage pool.
     treasury.garage_pool = treasury
         .garage_pool
         .checked_add(pool_fee)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    // Update treasury total garage TVL.
-    treasury.total_garage_tvl = treasury
-        .total_garage_tvl
-        .checked_add(net_amount)
-        .and_then(|v| v.checked_add(ref_fee))
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Transfer pool share into the Garage vault.
+    if pool_fee > 0 {
+        transfer(
+            signer_info,
+            sender_info,
+            garage_vault_info,
+            token_program,
+            pool_fee,
+        )?;
+    }
+
+    // Transfer team share to the fee collector.
+    if team_fee > 0 {
+        transfer(
+            signer_info,
+            sender_info,
+            team_tokens_info,
+            token_program,
+            team_fee,
+        )?;
+    }
 
-    // Burn ALL deposited tokens (principal is never withdrawable).
+    // Burn everything else: the burn share, the referral share (credited to the referrer's
+    // Garage instead), and any rounding remainder from the split.
+    let total_burned = amount
+        .checked_sub(pool_fee)
+        .and_then(|v| v.checked_sub(team_fee))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
     burn(
         signer_info,
         sender_info,
         mint_info,
         token_program,
-        amount,
+        total_burned,
     )?;
 
     sol_log(&format!(
         "🔥 Burned {} FUEL (deposit)",
-        amount_to_ui_amount(amount, TOKEN_DECIMALS)
+        amount_to_ui_amount(total_burned, TOKEN_DECIMALS)
     ));
 
-    // Transfer team fee in SOL to fee collector.
-    // Note: Team fee is taken from the burned tokens conceptually.
-    // The actual SOL transfer for team fee would need to come from elsewhere,
-    // or we mint FUEL for the team. For now, we just track it.
-    // TODO: Implement team fee distribution mechanism.
-
     // Emit event.
     program_log(
         &[board_info.clone(), ore_program.clone()],
@@ -220,9 +459,11 @@ pub fn process_fuel_up(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             authority: *signer_info.key,
             gross_amount: amount,
             net_amount,
+            burn_amount,
             team_fee,
             ref_fee,
             pool_fee,
+            ref_redirected,
             referrer: garage.referrer,
             new_total_deposited: garage.total_deposited,
             new_max_payout: garage.max_payout,
@@ -232,9 +473,9 @@ pub fn process_fuel_up(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     )?;
 
     sol_log(&format!(
-        "⛽ Fuel Up: {} FUEL deposited (net: {}, team: {}, ref: {}, pool: {})",
+        "⛽ Fuel Up: {} FUEL deposited (burn: {}, team: {}, ref: {}, pool: {})",
         amount_to_ui_amount(amount, TOKEN_DECIMALS),
-        amount_to_ui_amount(net_amount, TOKEN_DECIMALS),
+        amount_to_ui_amount(burn_amount, TOKEN_DECIMALS),
         amount_to_ui_amount(team_fee, TOKEN_DECIMALS),
         amount_to_ui_amount(ref_fee, TOKEN_DECIMALS),
         amount_to_ui_amount(pool_fee, TOKEN_DECIMALS),
@@ -242,3 +483,77 @@ pub fn process_fuel_up(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
     Ok(())
 }
+
+/// Logs the start of a new Garage cycle.
+pub fn emit_new_cycle<'a>(
+    board_info: &AccountInfo<'a>,
+    ore_program: &AccountInfo<'a>,
+    event: NewCycleEvent,
+) -> ProgramResult {
+    program_log(&[board_info.clone(), ore_program.clone()], event.to_bytes())?;
+    sol_log(&format!("🔄 Garage cycle {} complete - starting new cycle", event.cycle));
+    Ok(())
+}
+
+/// Migrates a Garage account to the current layout, with `payer_info` covering the rent.
+///
+/// A program-owned payer (a GarageAutomation on keeper paths) is debited directly; any other
+/// payer must be a signer. Returns the rent paid, which keepers deduct from the automation
+/// balance.
+pub fn migrate_garage(
+    garage_info: &AccountInfo<'_>,
+    payer_info: &AccountInfo<'_>,
+) -> Result<u64, ProgramError> {
+    let garage_len = 8 + std::mem::size_of::<Garage>();
+    if garage_info.data_is_empty() || garage_info.data_len() >= garage_len {
+        return Ok(0);
+    }
+    let required = Rent::get()?.minimum_balance(garage_len);
+    let current = Rent::get()?.minimum_balance(garage_info.data_len());
+    let rent = required.saturating_sub(current);
+    if *payer_info.owner == speedway_api::ID {
+        payer_info.send(rent, garage_info);
+    } else {
+        garage_info.collect(rent, payer_info)?;
+    }
+    garage_info.realloc(garage_len, true)?;
+    Ok(rent)
+}
+
+/// Follows GarageForward records from a closed Garage to the Garage its position moved to.
+///
+/// `garage_info` is the validated Garage PDA of `referrer`. While it is closed, the next
+/// accounts in `upline_infos` must be the GarageForward PDA of the current referrer and, if
+/// that exists, the Garage PDA of its recipient. `referrer` is updated to the owner of the
+/// returned Garage. Returns None if the chain ends at a closed Garage without a forward, or
+/// forwards back to `signer`.
+pub fn resolve_garage<'a, 'info>(
+    signer: &Pubkey,
+    referrer: &mut Pubkey,
+    garage_info: &'a AccountInfo<'info>,
+    upline_infos: &mut std::slice::Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<&'a AccountInfo<'info>>, ProgramError> {
+    let mut garage_info = garage_info;
+    while garage_info.data_is_empty() {
+        let forward_info = upline_infos
+            .next()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        forward_info.has_seeds(&[GARAGE_FORWARD, &referrer.to_bytes()], &speedway_api::ID)?;
+        if forward_info.data_is_empty() {
+            return Ok(None);
+        }
+        *referrer = forward_info
+            .as_account::<GarageForward>(&speedway_api::ID)?
+            .recipient;
+        if *referrer == *signer {
+            return Ok(None);
+        }
+        garage_info = upline_infos
+            .next()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        garage_info
+            .is_writable()?
+            .has_seeds(&[GARAGE, &referrer.to_bytes()], &speedway_api::ID)?;
+    }
+    Ok(Some(garage_info))
+}
@@ -0,0 +1,264 @@
+use speedway_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+use crate::boost::{apply_boost_tax, BoostTax};
+use crate::collect::{apply_collect_tax, pay_from_vault_or_backstop, CollectTax};
+use crate::fuel_up::migrate_garage;
+
+/// Harvest: Compound part of accrued Garage rewards and withdraw the rest in one instruction.
+///
+/// The harvested amount (0 = all available) is split by `boost_bps`:
+/// - Boosted part: 5% boost tax (2% team, 3% pool), net added to total_deposited. The pool
+///   share is retained without minting, as in Boost
+/// - Withdrawn part: 10% base collect tax (2% team, 8% pool), then whale tax on the
+///   remainder (30% team, 70% pool), net paid to the user's wallet
+///
+/// The whale tax is measured against the user's outstanding obligation before the harvest.
+/// Withdrawals are paid from the Garage vault, then a capped backstop mint.
+/// Any rewards left unharvested keep accruing.
+pub fn process_harvest(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Harvest::try_from_bytes(data)?;
+    let boost_bps = u64::from_le_bytes(args.boost_bps);
+    let amount = u64::from_le_bytes(args.amount);
+    if boost_bps > DENOMINATOR_BPS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, garage_info, config_info, treasury_info, garage_vault_info, recipient_info, mint_info, team_tokens_info, board_info, system_program, token_program, associated_token_program, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer.
+    signer_info.is_signer()?;
+
+    // Validate garage PDA.
+    garage_info
+        .is_writable()?
+        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    migrate_garage(garage_info, signer_info)?;
+    let garage = garage_info
+        .as_account_mut::<Garage>(&speedway_api::ID)?
+        .assert_mut(|g| g.authority == *signer_info.key)?;
+
+    // Validate config.
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(|c| !c.is_paused(PAUSE_GARAGE_WITHDRAW), OreError::Paused.into())?
+        .assert_err(
+            |c| c.fee_collector != Pubkey::default(),
+            OreError::RoleNotSet.into(),
+        )?;
+
+    // Validate treasury.
+    let treasury = treasury_info
+        .is_writable()?
+        .has_seeds(&[TREASURY], &speedway_api::ID)?
+        .as_account_mut::<Treasury>(&speedway_api::ID)?;
+
+    // Validate Garage vault.
+    garage_vault_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_VAULT], &speedway_api::ID)?
+        .as_token_account()?
+        .assert(|t| t.mint() == MINT_ADDRESS)?
+        .assert(|t| t.owner() == *treasury_info.key)?;
+
+    // Validate mint.
+    mint_info.has_address(&MINT_ADDRESS)?.is_writable()?;
+
+    // Validate team token account.
+    team_tokens_info
+        .is_writable()?
+        .as_associated_token_account(&config.fee_collector, &MINT_ADDRESS)?;
+
+    // Validate board (for logging).
+    board_info.as_account::<Board>(&speedway_api::ID)?;
+
+    // Validate programs.
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    ore_program.is_program(&speedway_api::ID)?;
+
+    // Check if garage is exhausted.
+    if garage.is_exhausted() {
+        return Err(OreError::GarageExhausted.into());
+    }
+
+    // Calculate available rewards.
+    let available =
+        garage.calculate_available(clock.unix_timestamp, config.params.daily_rate_bps);
+    if available == 0 {
+        return Err(OreError::NoRewardsAvailable.into());
+    }
+
+    // Determine amount to harvest (0 = all available). The remainder keeps accruing.
+    let amount = if amount == 0 { available } else { amount };
+    if amount > available {
+        return Err(OreError::AmountExceedsAvailable.into());
+    }
+
+    // Split harvest into boosted and withdrawn parts.
+    let boost_amount = amount
+        .checked_mul(boost_bps)
+        .and_then(|v| v.checked_div(DENOMINATOR_BPS))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let collect_amount = amount
+        .checked_sub(boost_amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Boost tax on the boosted part.
+    let BoostTax {
+        tax: boost_tax,
+        team_fee: boost_team_fee,
+        pool_fee: boost_pool_fee,
+        net_amount: boost_net,
+    } = apply_boost_tax(boost_amount)?;
+
+    // Base collect tax and whale tax on the withdrawn part.
+    treasury.track_garage(garage)?;
+    let outstanding_before = garage.outstanding();
+    let CollectTax {
+        base_tax,
+        base_tax_team,
+        base_tax_pool,
+        whale_tax,
+        whale_tax_team,
+        whale_tax_pool,
+        net_amount: collect_net,
+    } = apply_collect_tax(
+        collect_amount,
+        outstanding_before,
+        treasury.total_garage_outstanding,
+        &config.params,
+    )?;
+
+    // Update garage.
+    // Boosted net is added to deposits; the full harvest is marked as claimed.
+    garage.total_deposited = garage
+        .total_deposited
+        .checked_add(boost_net)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    garage.total_claimed = garage
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    garage.settle(
+        clock.unix_timestamp,
+        available - amount,
+        config.params.daily_rate_bps,
+    );
+    garage.update_max_payout(config.params.max_payout_mult);
+
+    // Check if now exhausted.
+    let is_exhausted = garage.is_exhausted();
+
+    // Update treasury Garage totals (boosted net was added to deposits).
+    treasury.update_garage_outstanding(outstanding_before, garage.outstanding())?;
+    treasury.total_garage_tvl = treasury
+        .total_garage_tvl
+        .checked_add(boost_net)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Pay withdrawn part to user.
+    let mut backstop_minted = 0;
+    if collect_net > 0 {
+        // Create recipient token account if needed.
+        if recipient_info.data_is_empty() {
+            create_associated_token_account(
+                signer_info,
+                signer_info,
+                recipient_info,
+                mint_info,
+                system_program,
+                token_program,
+                associated_token_program,
+            )?;
+        } else {
+            recipient_info
+                .is_writable()?
+                .as_associated_token_account(signer_info.key, &MINT_ADDRESS)?;
+        }
+
+        // Pay from the Garage vault first, then mint the shortfall as a capped backstop.
+        backstop_minted = pay_from_vault_or_backstop(
+            collect_net,
+            config,
+            treasury,
+            treasury_info,
+            garage_vault_info,
+            recipient_info,
+            mint_info,
+            token_program,
+        )?;
+    }
+
+    // Mint team portions of all taxes to team token account.
+    let total_team_fee = boost_team_fee
+        .checked_add(base_tax_team)
+        .and_then(|v| v.checked_add(whale_tax_team))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if total_team_fee > 0 {
+        mint_to_signed(
+            mint_info,
+            team_tokens_info,
+            treasury_info,
+            token_program,
+            total_team_fee,
+            &[TREASURY],
+        )?;
+    }
+
+    // Emit event.
+    program_log(
+        &[board_info.clone(), ore_program.clone()],
+        HarvestEvent {
+            disc: OreEvent::Harvest as u64,
+            authority: *signer_info.key,
+            gross_amount: amount,
+            boost_amount,
+            boost_net,
+            boost_tax,
+            boost_team_fee,
+            boost_pool_fee,
+            collect_amount,
+            collect_net,
+            base_tax,
+            base_tax_team,
+            base_tax_pool,
+            whale_tax,
+            whale_tax_team,
+            whale_tax_pool,
+            backstop_minted,
+            new_total_deposited: garage.total_deposited,
+            new_max_payout: garage.max_payout,
+            new_total_claimed: garage.total_claimed,
+            is_exhausted: if is_exhausted { 1 } else { 0 },
+            _padding: [0; 7],
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    sol_log(&format!(
+        "🌾 Harvest: {} FUEL (boosted: {}, withdrawn: {}, net: {})",
+        amount_to_ui_amount(amount, TOKEN_DECIMALS),
+        amount_to_ui_amount(boost_amount, TOKEN_DECIMALS),
+        amount_to_ui_amount(collect_amount, TOKEN_DECIMALS),
+        amount_to_ui_amount(collect_net, TOKEN_DECIMALS),
+    ));
+
+    if is_exhausted {
+        sol_log("🏁 Garage exhausted - max payout reached");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use speedway_api::prelude::*;
+use solana_program::program_pack::Pack;
+use steel::*;
+
+/// Creates the Garage vault token account, with the signer paying the rent.
+///
+/// Permissionless and not timelocked, so deployments initialized before the Garage vault
+/// existed can open it without an admin change. A no-op once the vault exists.
+pub fn process_init_garage_vault(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, treasury_info, garage_vault_info, mint_info, system_program, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    treasury_info
+        .has_seeds(&[TREASURY], &speedway_api::ID)?
+        .as_account::<Treasury>(&speedway_api::ID)?;
+    garage_vault_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_VAULT], &speedway_api::ID)?;
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
+
+    if !garage_vault_info.data_is_empty() {
+        return Ok(());
+    }
+
+    create_garage_vault(
+        garage_vault_info,
+        treasury_info,
+        mint_info,
+        system_program,
+        signer_info,
+    )
+}
+
+/// Creates the Garage vault as a FUEL token account owned by the treasury, with `payer_info`
+/// covering the rent.
+pub fn create_garage_vault<'a>(
+    garage_vault_info: &AccountInfo<'a>,
+    treasury_info: &AccountInfo<'a>,
+    mint_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    allocate_account(
+        garage_vault_info,
+        system_program,
+        payer_info,
+        spl_token::state::Account::LEN,
+        &spl_token::ID,
+        &[GARAGE_VAULT],
+    )?;
+    solana_program::program::invoke(
+        &spl_token::instruction::initialize_account3(
+            &spl_token::ID,
+            garage_vault_info.key,
+            mint_info.key,
+            treasury_info.key,
+        )?,
+        &[garage_vault_info.clone(), mint_info.clone()],
+    )
+}
@@ -2,13 +2,17 @@ use speedway_api::prelude::*;
 use solana_program::clock::Clock;
 use steel::*;
 
+use crate::init_garage_vault::create_garage_vault;
+
 /// Initializes all required program PDAs. Can only be called once by the admin.
 ///
 /// Creates:
 /// - Treasury: Program's global token authority and yield pool
-/// - Config: Stores admin address and program settings
+/// - Config: Stores role addresses and program settings
 /// - Board: Game state (current round, slots, epoch)
 /// - Round 0: First game round
+/// - House Garage: Fallback referrer for users without a referral code
+/// - Garage Vault: FUEL token account holding the Garage pool (authority: Treasury)
 ///
 /// Accounts:
 /// 0. `[signer]` Admin (must match ADMIN_ADDRESS)
@@ -16,17 +20,22 @@ use steel::*;
 /// 2. `[writable]` Config PDA
 /// 3. `[writable]` Board PDA
 /// 4. `[writable]` Round 0 PDA
-/// 5. `[]` System program
-/// 6. `[]` Speedway program (self)
+/// 5. `[writable]` House Garage PDA
+/// 6. `[writable]` Garage Vault PDA
+/// 7. `[]` FUEL mint
+/// 8. `[]` System program
+/// 9. `[]` Token program
+/// 10. `[]` Speedway program (self)
 pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Parse accounts
-    let [signer_info, treasury_info, config_info, board_info, round_info, system_program, ore_program] =
+    let [signer_info, treasury_info, config_info, board_info, round_info, house_garage_info, garage_vault_info, mint_info, system_program, token_program, ore_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    // Validate signer is the admin
+    // Validate signer is the admin. ADMIN_ADDRESS only gates initialization; afterwards
+    // every role lives in Config and moves via ProposeRoleTransfer/AcceptRoleTransfer.
     signer_info.is_signer()?;
     if *signer_info.key != ADMIN_ADDRESS {
         return Err(OreError::NotAuthorized.into());
@@ -34,6 +43,7 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
     // Validate programs
     system_program.is_program(&system_program::ID)?;
+    token_program.is_program(&spl_token::ID)?;
     ore_program.is_program(&speedway_api::ID)?;
 
     // Get clock for current slot
@@ -56,11 +66,11 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
     let treasury = treasury_info.as_account_mut::<Treasury>(&speedway_api::ID)?;
     treasury.balance = 0;
-    treasury.buffer_a = 0;
+    treasury.garage_backstop_minted = 0;
     treasury.motherlode = 0;
     treasury.miner_rewards_factor = Numeric::ZERO;
     treasury.stake_rewards_factor = Numeric::ZERO;
-    treasury.buffer_b = 0;
+    treasury.total_garage_outstanding = 0;
     treasury.total_refined = 0;
     treasury.total_staked = 0;
     treasury.total_unclaimed = 0;
@@ -83,11 +93,16 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
     let config = config_info.as_account_mut::<Config>(&speedway_api::ID)?;
     config.admin = ADMIN_ADDRESS;
-    config.buffer_a = [0u8; 32];
-    config.buffer_b = [0u8; 32];
-    config.buffer_c = [0u8; 32];
-    config.buffer_d = [0u8; 32];
-    config.buffer_e = [0u8; 8];
+    config.fee_collector = ADMIN_FEE_COLLECTOR;
+    config.timelock_delay = MIN_TIMELOCK_DELAY;
+    config.change_count = 0;
+    config.paused = 0;
+    config.buffer_b = [0u8; 8];
+    config.treasury_operator = BURY_AUTHORITY;
+    config.pauser = ADMIN_ADDRESS;
+    config.garage_backstop_cap = 0;
+    config.version = CONFIG_VERSION;
+    config.params = ProtocolParams::default();
 
     // Create Board PDA
     board_info
@@ -106,8 +121,13 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     let board = board_info.as_account_mut::<Board>(&speedway_api::ID)?;
     board.round_id = 0;
     board.start_slot = current_slot;
-    board.end_slot = current_slot.saturating_add(ONE_MINUTE_SLOTS);
+    board.end_slot = current_slot.saturating_add(config.params.round_slots);
     board.epoch_id = 0;
+    board.table_id = DEFAULT_TABLE_ID as u64;
+    board.min_deploy = 0;
+    board.round_slots = 0;
+    board.intermission_slots = 0;
+    board.var = VAR_ADDRESS;
 
     // Create Round 0 PDA
     let round_id: u64 = 0;
@@ -139,11 +159,58 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     round.total_vaulted = 0;
     round.total_winnings = 0;
 
+    // Create House Garage PDA
+    house_garage_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[GARAGE, &HOUSE_ADDRESS.to_bytes()], &speedway_api::ID)?;
+
+    create_program_account::<Garage>(
+        house_garage_info,
+        ore_program,
+        signer_info,
+        &speedway_api::ID,
+        &[GARAGE, &HOUSE_ADDRESS.to_bytes()],
+    )?;
+
+    let house_garage = house_garage_info.as_account_mut::<Garage>(&speedway_api::ID)?;
+    house_garage.authority = HOUSE_ADDRESS;
+    house_garage.referrer = Pubkey::default();
+    house_garage.total_deposited = 0;
+    house_garage.total_claimed = 0;
+    house_garage.max_payout = 0;
+    house_garage.last_action_at = clock.unix_timestamp;
+    house_garage.created_at = clock.unix_timestamp;
+    house_garage.direct_referrals = 0;
+    house_garage.outstanding_tracked = 1;
+    house_garage.lifetime_ref_earnings = 0;
+    house_garage.cycle = 0;
+    house_garage.lifetime_deposited = 0;
+    house_garage.lifetime_claimed = 0;
+    house_garage.level_referrals = [0; REFERRAL_LEVELS];
+    house_garage.level_ref_earnings = [0; REFERRAL_LEVELS];
+
+    // Create Garage Vault PDA (FUEL token account owned by the treasury)
+    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    garage_vault_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[GARAGE_VAULT], &speedway_api::ID)?;
+    create_garage_vault(
+        garage_vault_info,
+        treasury_info,
+        mint_info,
+        system_program,
+        signer_info,
+    )?;
+
     solana_program::msg!("Speedway program initialized successfully!");
     solana_program::msg!("Treasury: {}", treasury_info.key);
     solana_program::msg!("Config: {}", config_info.key);
     solana_program::msg!("Board: {}", board_info.key);
     solana_program::msg!("Round 0: {}", round_info.key);
+    solana_program::msg!("House Garage: {}", house_garage_info.key);
+    solana_program::msg!("Garage Vault: {}", garage_vault_info.key);
 
     Ok(())
 }
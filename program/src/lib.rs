@@ -1,6 +1,9 @@
+mod accept_role_transfer;
 mod automate;
+mod automate_garage;
 mod bury;
 mod buyback;
+mod cancel_change;
 mod checkpoint;
 mod claim_ore;
 mod claim_sol;
@@ -10,26 +13,43 @@ mod close;
 // mod compound_yield;
 mod deploy;
 // mod deposit;
+mod execute_change;
+mod init_garage_vault;
 mod initialize;
 mod liq;
 mod log;
+mod migrate_board;
+mod migrate_config;
 mod new_var;
+mod propose_change;
+mod propose_role_transfer;
 mod reload_sol;
 mod reset;
-mod set_admin;
+mod set_fee_collector;
+mod set_pause;
+mod set_table;
 // mod withdraw;
 mod wrap;
 
 // Garage modules
+mod accept_garage_transfer;
 mod boost;
+mod claim_referral_code;
 mod claim_wallet;
 mod collect;
 mod fuel_up;
+mod harvest;
+mod propose_garage_transfer;
+mod release_referral_code;
 mod stash;
+mod transfer_referral_code;
 
+use accept_role_transfer::*;
 use automate::*;
+use automate_garage::*;
 use bury::*;
 use buyback::*;
+use cancel_change::*;
 use checkpoint::*;
 use claim_ore::*;
 use claim_sol::*;
@@ -39,22 +59,36 @@ use close::*;
 // use compound_yield::*;
 use deploy::*;
 // use deposit::*;
+use execute_change::*;
+use init_garage_vault::*;
 use initialize::*;
 use liq::*;
 use log::*;
+use migrate_board::*;
+use migrate_config::*;
 use new_var::*;
+use propose_change::*;
+use propose_role_transfer::*;
 use reload_sol::*;
 use reset::*;
-use set_admin::*;
+use set_fee_collector::*;
+use set_pause::*;
+use set_table::*;
 // use withdraw::*;
 use wrap::*;
 
 // Garage imports
+use accept_garage_transfer::*;
 use boost::*;
+use claim_referral_code::*;
 use claim_wallet::*;
 use collect::*;
 use fuel_up::*;
+use harvest::*;
+use propose_garage_transfer::*;
+use release_referral_code::*;
 use stash::*;
+use transfer_referral_code::*;
 
 use speedway_api::instruction::*;
 use solana_security_txt::security_txt;
@@ -74,6 +108,7 @@ pub fn process_instruction(
         OreInstruction::ClaimSOL => process_claim_sol(accounts, data)?,
         OreInstruction::ClaimORE => process_claim_ore(accounts, data)?,
         OreInstruction::Deploy => process_deploy(accounts, data)?,
+        OreInstruction::DeployMulti => process_deploy_multi(accounts, data)?,
         OreInstruction::Log => process_log(accounts, data)?,
         OreInstruction::Close => process_close(accounts, data)?,
         OreInstruction::Reset => process_reset(accounts, data)?,
@@ -91,12 +126,32 @@ pub fn process_instruction(
         OreInstruction::Collect => process_collect(accounts, data)?,
         OreInstruction::Stash => process_stash(accounts, data)?,
         OreInstruction::ClaimWallet => process_claim_wallet(accounts, data)?,
+        OreInstruction::Harvest => process_harvest(accounts, data)?,
+        OreInstruction::AutomateGarage => process_automate_garage(accounts, data)?,
+        OreInstruction::ProposeGarageTransfer => {
+            process_propose_garage_transfer(accounts, data)?
+        }
+        OreInstruction::AcceptGarageTransfer => process_accept_garage_transfer(accounts, data)?,
+        OreInstruction::ClaimReferralCode => process_claim_referral_code(accounts, data)?,
+        OreInstruction::ReleaseReferralCode => process_release_referral_code(accounts, data)?,
+        OreInstruction::TransferReferralCode => process_transfer_referral_code(accounts, data)?,
+        OreInstruction::FuelUpWithCode => process_fuel_up_with_code(accounts, data)?,
 
         // Admin
         OreInstruction::Buyback => process_buyback(accounts, data)?,
         OreInstruction::Bury => process_bury(accounts, data)?,
         OreInstruction::Wrap => process_wrap(accounts, data)?,
-        OreInstruction::SetAdmin => process_set_admin(accounts, data)?,
+        OreInstruction::SetFeeCollector => process_set_fee_collector(accounts, data)?,
+        OreInstruction::ProposeChange => process_propose_change(accounts, data)?,
+        OreInstruction::ExecuteChange => process_execute_change(accounts, data)?,
+        OreInstruction::CancelChange => process_cancel_change(accounts, data)?,
+        OreInstruction::ProposeRoleTransfer => process_propose_role_transfer(accounts, data)?,
+        OreInstruction::AcceptRoleTransfer => process_accept_role_transfer(accounts, data)?,
+        OreInstruction::SetPause => process_set_pause(accounts, data)?,
+        OreInstruction::SetTable => process_set_table(accounts, data)?,
+        OreInstruction::MigrateBoard => process_migrate_board(accounts, data)?,
+        OreInstruction::MigrateConfig => process_migrate_config(accounts, data)?,
+        OreInstruction::InitGarageVault => process_init_garage_vault(accounts, data)?,
         OreInstruction::NewVar => process_new_var(accounts, data)?,
         OreInstruction::Liq => process_liq(accounts, data)?,
         OreInstruction::Initialize => process_initialize(accounts, data)?,
@@ -9,12 +9,19 @@ const LIQ_MANAGER: Pubkey = pubkey!("DJqfQWB8tZE6fzqWa8okncDh7ciTuD8QQKp1ssNETWe
 /// Send SOL to the liq manager.
 pub fn process_liq(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
-    let [signer_info, board_info, _config_info, manager_info, manager_sol_info, treasury_info, treasury_sol_info, token_program, ore_program] =
+    let [signer_info, board_info, config_info, manager_info, manager_sol_info, treasury_info, treasury_sol_info, token_program, ore_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?.has_address(&BURY_AUTHORITY)?;
+    signer_info.is_signer()?;
+    config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(
+            |c| c.has_role(Role::TreasuryOperator, signer_info.key),
+            OreError::NotAuthorized.into(),
+        )?;
     board_info.as_account_mut::<Board>(&speedway_api::ID)?;
     manager_info.has_address(&LIQ_MANAGER)?;
     manager_sol_info
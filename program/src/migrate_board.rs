@@ -0,0 +1,46 @@
+use speedway_api::prelude::*;
+use solana_program::{rent::Rent, sysvar::Sysvar};
+use steel::*;
+
+/// Grows a table's Board to the current layout, with the signer paying the rent.
+///
+/// Permissionless and not timelocked, so tables created before a Board layout change keep
+/// running after an upgrade without waiting on an admin change.
+pub fn process_migrate_board(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = MigrateBoard::try_from_bytes(data)?;
+    let table_id = args.table_id;
+
+    // Load accounts.
+    let [signer_info, board_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    board_info
+        .is_writable()?
+        .has_seeds(&[BOARD, table_seed(&table_id)], &speedway_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    migrate_board(board_info, signer_info)
+}
+
+/// Migrates a Board to the current layout, with `payer_info` covering the rent.
+pub fn migrate_board(board_info: &AccountInfo<'_>, payer_info: &AccountInfo<'_>) -> ProgramResult {
+    let board_len = 8 + std::mem::size_of::<Board>();
+    if board_info.data_is_empty() || board_info.data_len() >= board_len {
+        return Ok(());
+    }
+    let required = Rent::get()?.minimum_balance(board_len);
+    let lamports = board_info.lamports();
+    if lamports < required {
+        board_info.collect(required - lamports, payer_info)?;
+    }
+    board_info.realloc(board_len, true)?;
+
+    // The default table predates pinned vars and samples the original var.
+    let board = board_info.as_account_mut::<Board>(&speedway_api::ID)?;
+    if board.table_id == DEFAULT_TABLE_ID as u64 && board.var == Pubkey::default() {
+        board.var = VAR_ADDRESS;
+    }
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use speedway_api::prelude::*;
+use solana_program::{log::sol_log, rent::Rent, sysvar::Sysvar};
+use steel::*;
+
+/// Grows Config to the current layout, with the signer paying the rent.
+///
+/// Permissionless and not timelocked, so the program keeps running after an upgrade without
+/// waiting on an admin change. Configs below CONFIG_VERSION have their unset roles backfilled
+/// and ProtocolParams::default() written, since parameters stored under an older layout
+/// cannot be read in the current one. The admin can then change them with ProposeChange.
+/// The timelock delay is raised to MIN_TIMELOCK_DELAY if it is below the floor.
+pub fn process_migrate_config(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &speedway_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    migrate_config(config_info, signer_info)
+}
+
+/// Migrates Config to the current layout, with `payer_info` covering the rent.
+///
+/// Roles stored in buffers that predate them are zero on older Configs, so any unset role
+/// is backfilled with its default holder, and the default parameters are written. The
+/// timelock delay is raised to the floor on every call.
+pub fn migrate_config(config_info: &AccountInfo<'_>, payer_info: &AccountInfo<'_>) -> ProgramResult {
+    let config_len = 8 + std::mem::size_of::<Config>();
+    if config_info.data_len() < config_len {
+        let required = Rent::get()?.minimum_balance(config_len);
+        let lamports = config_info.lamports();
+        if lamports < required {
+            config_info.collect(required - lamports, payer_info)?;
+        }
+        config_info.realloc(config_len, true)?;
+    }
+
+    let config = config_info.as_account_mut::<Config>(&speedway_api::ID)?;
+    if config.version < CONFIG_VERSION {
+        sol_log(&format!(
+            "Config migrated from version {} to {}",
+            config.version, CONFIG_VERSION
+        ));
+        config.backfill_roles();
+        config.params = ProtocolParams::default();
+        config.version = CONFIG_VERSION;
+    }
+
+    // Configs from before the timelock floor may run with no delay at all.
+    if config.timelock_delay < MIN_TIMELOCK_DELAY {
+        config.timelock_delay = MIN_TIMELOCK_DELAY;
+    }
+    Ok(())
+}
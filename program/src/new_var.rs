@@ -1,31 +1,68 @@
 use speedway_api::prelude::*;
 use steel::*;
 
-/// Creates a new var account.
+/// Creates a new var account and pins it as the board's var.
+///
+/// Deploy and Reset only accept the var stored on the board. A board without a var (a table
+/// just opened by SetTable) takes one directly. Replacing a pinned var requires a ready Var
+/// change for the table, passed with its proposer after the other accounts and consumed here.
 pub fn process_new_var(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = NewVar::try_from_bytes(data)?;
     let id = u64::from_le_bytes(args.id);
     let commit = args.commit;
     let samples = u64::from_le_bytes(args.samples);
+    let table_id = args.table_id;
 
     // Load accounts.
+    let clock = Clock::get()?;
+    let (var_accounts, change_accounts) = accounts.split_at(accounts.len().min(7));
     let [signer_info, board_info, config_info, provider_info, var_info, system_program, entropy_program] =
-        accounts
+        var_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    let board = board_info.as_account_mut::<Board>(&speedway_api::ID)?;
+    let board = board_info
+        .is_writable()?
+        .has_seeds(&[BOARD, table_seed(&table_id)], &speedway_api::ID)?
+        .as_account_mut::<Board>(&speedway_api::ID)?;
     config_info
-        .as_account_mut::<Config>(&speedway_api::ID)?
-        .assert_mut_err(
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(
             |c| c.admin == *signer_info.key,
             OreError::NotAuthorized.into(),
         )?;
     entropy_program.is_program(&entropy_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
+    // Consume the timelocked change when replacing a pinned var.
+    if board.var != Pubkey::default() {
+        let [pending_change_info, proposer_info] = change_accounts else {
+            return Err(OreError::TimelockActive.into());
+        };
+        let pending_change = *pending_change_info
+            .is_writable()?
+            .as_account::<PendingChange>(&speedway_api::ID)?
+            .assert_err(
+                |p| p.kind == ChangeKind::Var as u64 && p.value == table_id as u64,
+                OreError::InvalidParams.into(),
+            )?
+            .assert_err(
+                |p| p.is_ready(clock.unix_timestamp),
+                OreError::TimelockNotElapsed.into(),
+            )?;
+        pending_change_info.has_seeds(
+            &[PENDING_CHANGE, &pending_change.id.to_le_bytes()],
+            &speedway_api::ID,
+        )?;
+        proposer_info
+            .is_writable()?
+            .has_address(&pending_change.proposer)?;
+        pending_change_info.close(proposer_info)?;
+    }
+
     invoke_signed(
         &entropy_api::sdk::open(
             *board_info.key,
@@ -45,8 +82,11 @@ pub fn process_new_var(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             system_program.clone(),
         ],
         &entropy_api::ID,
-        &[BOARD],
+        &[BOARD, table_seed(&table_id)],
     )?;
 
+    // Pin the var to the board.
+    board.var = *var_info.key;
+
     Ok(())
 }
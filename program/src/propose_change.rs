@@ -0,0 +1,93 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Queues a timelocked change to Config or a table.
+///
+/// The change is validated now and again on execution. Its ETA must be at least the
+/// configured timelock delay in the future, giving users notice before it takes effect.
+pub fn process_propose_change(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = ProposeChange::try_from_bytes(data)?;
+    let kind = args.kind as u64;
+    let value = u64::from_le_bytes(args.value);
+    let eta = i64::from_le_bytes(args.eta);
+    let params = args.params.params();
+    let min_deploy = u64::from_le_bytes(args.min_deploy);
+    let round_slots = u64::from_le_bytes(args.round_slots);
+    let intermission_slots = u64::from_le_bytes(args.intermission_slots);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, pending_change_info, board_info, system_program, ore_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account_mut::<Config>(&speedway_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    let id = config.change_count;
+    pending_change_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[PENDING_CHANGE, &id.to_le_bytes()], &speedway_api::ID)?;
+    board_info.as_account::<Board>(&speedway_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&speedway_api::ID)?;
+
+    // Enforce the timelock delay.
+    if eta < clock.unix_timestamp.saturating_add(config.timelock_delay as i64) {
+        return Err(OreError::TimelockTooShort.into());
+    }
+
+    // Create pending change.
+    create_program_account::<PendingChange>(
+        pending_change_info,
+        system_program,
+        signer_info,
+        &speedway_api::ID,
+        &[PENDING_CHANGE, &id.to_le_bytes()],
+    )?;
+    let pending_change = pending_change_info.as_account_mut::<PendingChange>(&speedway_api::ID)?;
+    pending_change.id = id;
+    pending_change.kind = kind;
+    pending_change.proposer = *signer_info.key;
+    pending_change.value = value;
+    pending_change.params = params;
+    pending_change.min_deploy = min_deploy;
+    pending_change.round_slots = round_slots;
+    pending_change.intermission_slots = intermission_slots;
+    pending_change.created_at = clock.unix_timestamp;
+    pending_change.eta = eta;
+    if !pending_change.is_valid() {
+        return Err(OreError::InvalidParams.into());
+    }
+    config.change_count += 1;
+
+    // Emit event.
+    program_log(
+        &[board_info.clone(), ore_program.clone()],
+        ChangeProposedEvent {
+            disc: OreEvent::ChangeProposed as u64,
+            id,
+            kind,
+            authority: *signer_info.key,
+            value,
+            params,
+            min_deploy,
+            round_slots,
+            intermission_slots,
+            eta,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
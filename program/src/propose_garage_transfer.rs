@@ -0,0 +1,75 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+use crate::fuel_up::migrate_garage;
+
+/// Proposes moving the signer's Garage position to a new owner.
+///
+/// The recipient must call AcceptGarageTransfer to complete the move.
+/// Passing Pubkey::default() as the recipient cancels a pending proposal.
+pub fn process_propose_garage_transfer(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, garage_info, garage_transfer_info, recipient_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    garage_info
+        .is_writable()?
+        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    migrate_garage(garage_info, signer_info)?;
+    garage_info
+        .as_account::<Garage>(&speedway_api::ID)?
+        .assert_err(
+            |g| g.authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    garage_transfer_info
+        .is_writable()?
+        .has_seeds(&[GARAGE_TRANSFER, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Cancel pending transfer if recipient is Pubkey::default().
+    if *recipient_info.key == Pubkey::default() {
+        garage_transfer_info
+            .as_account::<GarageTransfer>(&speedway_api::ID)?
+            .assert_err(
+                |t| t.authority == *signer_info.key,
+                OreError::NotAuthorized.into(),
+            )?;
+        garage_transfer_info.close(signer_info)?;
+        return Ok(());
+    }
+
+    // Cannot transfer to self.
+    if *recipient_info.key == *signer_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Open or update transfer proposal.
+    let transfer = if garage_transfer_info.data_is_empty() {
+        create_program_account::<GarageTransfer>(
+            garage_transfer_info,
+            system_program,
+            signer_info,
+            &speedway_api::ID,
+            &[GARAGE_TRANSFER, &signer_info.key.to_bytes()],
+        )?;
+        let transfer = garage_transfer_info.as_account_mut::<GarageTransfer>(&speedway_api::ID)?;
+        transfer.authority = *signer_info.key;
+        transfer
+    } else {
+        garage_transfer_info
+            .as_account_mut::<GarageTransfer>(&speedway_api::ID)?
+            .assert_mut_err(
+                |t| t.authority == *signer_info.key,
+                OreError::NotAuthorized.into(),
+            )?
+    };
+    transfer.recipient = *recipient_info.key;
+    transfer.created_at = clock.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Proposes handing a Config role to a new address.
+///
+/// Callable by the current holder of the role or by the admin. The recipient must call
+/// AcceptRoleTransfer to complete the handover, so a mistyped address can never take effect.
+/// Passing Pubkey::default() as the recipient cancels a pending proposal.
+///
+/// Replacing a pending proposal keeps its original authority, who paid the account rent and
+/// is refunded when it closes.
+pub fn process_propose_role_transfer(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = ProposeRoleTransfer::try_from_bytes(data)?;
+    let role = Role::try_from(args.role).map_err(|_| ProgramError::InvalidArgument)?;
+
+    // Load accounts.
+    let [signer_info, config_info, role_transfer_info, recipient_info, system_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    open_role_transfer(
+        signer_info,
+        config_info,
+        role_transfer_info,
+        system_program,
+        role,
+        *recipient_info.key,
+    )
+}
+
+/// Opens, replaces or cancels the pending transfer of `role` to `recipient`.
+pub fn open_role_transfer<'a>(
+    signer_info: &AccountInfo<'a>,
+    config_info: &AccountInfo<'a>,
+    role_transfer_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    role: Role,
+    recipient: Pubkey,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    signer_info.is_signer()?;
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(
+            |c| c.has_role(role, signer_info.key) || c.has_role(Role::Admin, signer_info.key),
+            OreError::NotAuthorized.into(),
+        )?;
+    role_transfer_info
+        .is_writable()?
+        .has_seeds(&[ROLE_TRANSFER, &[role as u8]], &speedway_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Cancel pending transfer if recipient is Pubkey::default().
+    if recipient == Pubkey::default() {
+        role_transfer_info
+            .as_account::<RoleTransfer>(&speedway_api::ID)?
+            .assert_err(
+                |t| t.authority == *signer_info.key,
+                OreError::NotAuthorized.into(),
+            )?;
+        role_transfer_info.close(signer_info)?;
+        return Ok(());
+    }
+
+    // Cannot transfer to the current holder.
+    if recipient == config.role(role) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Open or replace transfer proposal. The rent payer stays the authority on replacement.
+    if role_transfer_info.data_is_empty() {
+        create_program_account::<RoleTransfer>(
+            role_transfer_info,
+            system_program,
+            signer_info,
+            &speedway_api::ID,
+            &[ROLE_TRANSFER, &[role as u8]],
+        )?;
+        let transfer = role_transfer_info.as_account_mut::<RoleTransfer>(&speedway_api::ID)?;
+        transfer.authority = *signer_info.key;
+    }
+    let transfer = role_transfer_info.as_account_mut::<RoleTransfer>(&speedway_api::ID)?;
+    transfer.role = role as u64;
+    transfer.recipient = recipient;
+    transfer.created_at = clock.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Releases a referral code owned by the signer, returning its rent.
+///
+/// Garages already referred through the code keep their referrer.
+pub fn process_release_referral_code(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, referral_code_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let referral_code = referral_code_info
+        .is_writable()?
+        .as_account::<ReferralCode>(&speedway_api::ID)?
+        .assert_err(
+            |c| c.authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    referral_code_info.has_seeds(&[REFERRAL_CODE, &referral_code.code], &speedway_api::ID)?;
+
+    // Close referral code.
+    referral_code_info.close(signer_info)?;
+
+    Ok(())
+}
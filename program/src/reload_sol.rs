@@ -2,6 +2,8 @@ use speedway_api::prelude::*;
 use solana_program::{log::sol_log, native_token::lamports_to_sol};
 use steel::*;
 
+use crate::automate::migrate_automation_from_balance;
+
 /// Claims a block reward.
 pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
@@ -10,6 +12,7 @@ pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+    migrate_automation_from_balance(automation_info)?;
     let automation = automation_info
         .as_account_mut::<Automation>(&speedway_api::ID)?
         .assert_mut(|a| a.executor == *signer_info.key || a.executor == EXECUTOR_ADDRESS)?
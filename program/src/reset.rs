@@ -3,34 +3,60 @@ use speedway_api::prelude::*;
 use solana_program::{keccak, log::sol_log};
 use steel::*;
 
+use crate::deploy::migrate_round;
+
 // TODO Integrate admin fee
 
 /// Pays out the winners and block reward.
-pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_reset(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Reset::try_from_bytes(data)?;
+    let table_id = args.table_id;
+
     // Load accounts.
     let clock = Clock::get()?;
     let (ore_accounts, other_accounts) = accounts.split_at(14);
     sol_log(&format!("Ore accounts: {:?}", ore_accounts.len()).to_string());
     sol_log(&format!("Other accounts: {:?}", other_accounts.len()).to_string());
-    let [signer_info, board_info, _config_info, fee_collector_info, mint_info, round_info, round_next_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
+    let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
         ore_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(|c| !c.is_paused(PAUSE_RESET), OreError::Paused.into())?
+        .assert_err(
+            |c| c.fee_collector != Pubkey::default(),
+            OreError::RoleNotSet.into(),
+        )?;
     let board = board_info
-        .as_account_mut::<Board>(&speedway_api::ID)?
-        .assert_mut(|b| clock.slot >= b.end_slot + INTERMISSION_SLOTS)?;
+        .has_seeds(&[BOARD, table_seed(&table_id)], &speedway_api::ID)?
+        .as_account_mut::<Board>(&speedway_api::ID)?;
     fee_collector_info
         .is_writable()?
-        .has_address(&ADMIN_FEE_COLLECTOR)?;
+        .has_address(&config.fee_collector)?;
+    round_info.is_writable()?.has_seeds(
+        &[ROUND, table_seed(&table_id), &board.round_id.to_le_bytes()],
+        &speedway_api::ID,
+    )?;
+    migrate_round(
+        round_info,
+        signer_info,
+        board.intermission_slots(&config.params),
+        config.params.checkpoint_window_slots(),
+    )?;
     let round = round_info
         .as_account_mut::<Round>(&speedway_api::ID)?
-        .assert_mut(|r| r.id == board.round_id)?;
-    round_next_info
-        .is_empty()?
-        .is_writable()?
-        .has_seeds(&[ROUND, &(board.round_id + 1).to_le_bytes()], &speedway_api::ID)?;
+        .assert_mut(|r| r.id == board.round_id)?
+        // Wait out the intermission the round started with.
+        .assert_mut(|r| clock.slot >= board.end_slot + r.intermission_slots)?;
+    round_next_info.is_empty()?.is_writable()?.has_seeds(
+        &[ROUND, table_seed(&table_id), &(board.round_id + 1).to_le_bytes()],
+        &speedway_api::ID,
+    )?;
     let mint = mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&speedway_api::ID)?;
     treasury_tokens_info.as_associated_token_account(&treasury_info.key, &mint_info.key)?;
@@ -45,7 +71,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         ore_program,
         signer_info,
         &speedway_api::ID,
-        &[ROUND, &(board.round_id + 1).to_le_bytes()],
+        &[ROUND, table_seed(&table_id), &(board.round_id + 1).to_le_bytes()],
     )?;
     let round_next = round_next_info.as_account_mut::<Round>(&speedway_api::ID)?;
     round_next.id = board.round_id + 1;
@@ -60,6 +86,8 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     round_next.total_deployed = 0;
     round_next.total_vaulted = 0;
     round_next.total_winnings = 0;
+    round_next.intermission_slots = 0;
+    round_next.checkpoint_window_slots = 0;
 
     // Sample random variable
     let (entropy_accounts, mint_accounts) = other_accounts.split_at(2);
@@ -69,7 +97,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     let var = var_info
-        .has_address(&VAR_ADDRESS)?
+        .has_address(&board.var)?
         .as_account::<Var>(&entropy_api::ID)?
         .assert(|v| v.authority == *board_info.key)?
         .assert(|v| v.slot_hash != [0; 32])?
@@ -96,7 +124,8 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         round.total_deployed = 0;
 
         // Emit event.
-        program_log(
+        table_log(
+            table_id,
             &[board_info.clone(), ore_program.clone()],
             ResetEvent {
                 disc: 0,
@@ -128,12 +157,12 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     // Calculate Sprint protocol fees (10% total = 1% team + 9% buyback).
     let team_fee = round
         .total_deployed
-        .checked_mul(SPRINT_TEAM_FEE_BPS)
+        .checked_mul(config.params.sprint_team_fee_bps)
         .and_then(|v| v.checked_div(DENOMINATOR_BPS))
         .ok_or(ProgramError::ArithmeticOverflow)?;
     let buyback_fee = round
         .total_deployed
-        .checked_mul(SPRINT_BUYBACK_FEE_BPS)
+        .checked_mul(config.params.sprint_buyback_fee_bps)
         .and_then(|v| v.checked_div(DENOMINATOR_BPS))
         .ok_or(ProgramError::ArithmeticOverflow)?;
     let total_protocol_fee = team_fee
@@ -157,7 +186,8 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
             .ok_or(ProgramError::ArithmeticOverflow)?;
 
         // Emit event.
-        program_log(
+        table_log(
+            table_id,
             &[board_info.clone(), ore_program.clone()],
             ResetEvent {
                 disc: 0,
@@ -200,11 +230,11 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
 
     // Calculate protocol fees on winnings: 1% team + 9% buyback = 10% total.
     let winnings_team_fee = raw_winnings
-        .checked_mul(SPRINT_TEAM_FEE_BPS)
+        .checked_mul(config.params.sprint_team_fee_bps)
         .and_then(|v| v.checked_div(DENOMINATOR_BPS))
         .ok_or(ProgramError::ArithmeticOverflow)?;
     let winnings_buyback_fee = raw_winnings
-        .checked_mul(SPRINT_BUYBACK_FEE_BPS)
+        .checked_mul(config.params.sprint_buyback_fee_bps)
         .and_then(|v| v.checked_div(DENOMINATOR_BPS))
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
@@ -299,7 +329,8 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     }
 
     // Emit event.
-    program_log(
+    table_log(
+        table_id,
         &[board_info.clone(), ore_program.clone()],
         ResetEvent {
             disc: 0,
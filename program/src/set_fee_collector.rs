@@ -0,0 +1,29 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+use crate::open_role_transfer;
+
+/// Proposes a new fee collector.
+///
+/// Shorthand for ProposeRoleTransfer with Role::FeeCollector. The new collector takes
+/// effect once it calls AcceptRoleTransfer, after which reset, FuelUp, Boost, Collect,
+/// Harvest and ClaimWallet route team fees to it.
+pub fn process_set_fee_collector(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetFeeCollector::try_from_bytes(data)?;
+    let fee_collector = Pubkey::new_from_array(args.fee_collector);
+
+    // Load accounts.
+    let [signer_info, config_info, role_transfer_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    open_role_transfer(
+        signer_info,
+        config_info,
+        role_transfer_info,
+        system_program,
+        Role::FeeCollector,
+        fee_collector,
+    )
+}
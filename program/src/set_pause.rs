@@ -0,0 +1,52 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Replaces the Config pause bitmask. Only callable by the pauser.
+///
+/// Not timelocked, so incidents can be contained immediately.
+pub fn process_set_pause(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetPause::try_from_bytes(data)?;
+    let mask = u64::from_le_bytes(args.mask);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, board_info, ore_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account_mut::<Config>(&speedway_api::ID)?
+        .assert_mut_err(
+            |c| c.has_role(Role::Pauser, signer_info.key),
+            OreError::NotAuthorized.into(),
+        )?;
+    board_info.as_account::<Board>(&speedway_api::ID)?;
+    ore_program.is_program(&speedway_api::ID)?;
+
+    // Reject unknown flags.
+    if mask & !PAUSE_ALL != 0 {
+        return Err(OreError::InvalidParams.into());
+    }
+
+    // Set pause mask.
+    let old_mask = config.paused;
+    config.paused = mask;
+
+    // Emit event.
+    program_log(
+        &[board_info.clone(), ore_program.clone()],
+        PauseUpdatedEvent {
+            disc: OreEvent::PauseUpdated as u64,
+            authority: *signer_info.key,
+            old_mask,
+            new_mask: mask,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
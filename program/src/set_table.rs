@@ -0,0 +1,95 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Opens a Sprint table.
+///
+/// Opening a table creates its Board and first Round; the admin must then open an entropy
+/// var for the new board with NewVar. The parameters of an open table are changed with a
+/// timelocked ProposeChange of kind Table.
+pub fn process_set_table(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetTable::try_from_bytes(data)?;
+    let table_id = args.table_id;
+    let min_deploy = u64::from_le_bytes(args.min_deploy);
+    let round_slots = u64::from_le_bytes(args.round_slots);
+    let intermission_slots = u64::from_le_bytes(args.intermission_slots);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, board_info, round_info, system_program, ore_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    board_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[BOARD, table_seed(&table_id)], &speedway_api::ID)?;
+    let round_id: u64 = 0;
+    round_info.is_empty()?.is_writable()?.has_seeds(
+        &[ROUND, table_seed(&table_id), &round_id.to_le_bytes()],
+        &speedway_api::ID,
+    )?;
+    system_program.is_program(&system_program::ID)?;
+    ore_program.is_program(&speedway_api::ID)?;
+
+    // Open table.
+    create_program_account::<Board>(
+        board_info,
+        system_program,
+        signer_info,
+        &speedway_api::ID,
+        &[BOARD, table_seed(&table_id)],
+    )?;
+    let board = board_info.as_account_mut::<Board>(&speedway_api::ID)?;
+    board.table_id = table_id as u64;
+    board.min_deploy = min_deploy;
+    board.round_slots = round_slots;
+    board.intermission_slots = intermission_slots;
+    if !board.has_valid_params() {
+        return Err(OreError::InvalidParams.into());
+    }
+    board.round_id = round_id;
+    board.start_slot = clock.slot;
+    board.end_slot = clock.slot.saturating_add(board.round_slots(&config.params));
+    board.epoch_id = 0;
+
+    // Open first round.
+    create_program_account::<Round>(
+        round_info,
+        system_program,
+        signer_info,
+        &speedway_api::ID,
+        &[ROUND, table_seed(&table_id), &round_id.to_le_bytes()],
+    )?;
+    let round = round_info.as_account_mut::<Round>(&speedway_api::ID)?;
+    round.id = round_id;
+    round.expires_at = u64::MAX;
+    round.rent_payer = *signer_info.key;
+    round.top_miner = Pubkey::default();
+
+    // Emit event.
+    table_log(
+        table_id,
+        &[board_info.clone(), ore_program.clone()],
+        TableUpdatedEvent {
+            disc: OreEvent::TableUpdated as u64,
+            table_id: table_id as u64,
+            authority: *signer_info.key,
+            min_deploy,
+            round_slots,
+            intermission_slots,
+            ts: clock.unix_timestamp,
+        }
+        .to_bytes(),
+    )?;
+
+    Ok(())
+}
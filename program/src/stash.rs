@@ -3,6 +3,8 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::fuel_up::{emit_new_cycle, migrate_garage};
+
 /// Stash: Send Sprint FUEL rewards directly to Garage.
 ///
 /// This is the frictionless path from Sprint rewards to Garage:
@@ -11,15 +13,17 @@ use steel::*;
 /// - Deposits directly into user's Garage (increases total_deposited)
 /// - Updates max_payout = total_deposited * 365 / 100
 /// - User must have an existing Garage account (use FuelUp first)
+/// - An exhausted Garage starts a new cycle (lifetime totals archived)
 ///
 /// Users are incentivized to Stash rather than ClaimORE + FuelUp because:
 /// - ClaimORE has 10% fee to other miners
-/// - FuelUp has 10% tax (2% team + 5% ref + 3% pool)
+/// - FuelUp splits 55% burn, 28% pool, 10% ref, 7% team (full amount credited)
 /// - Stash has 0% fee - full amount goes to Garage
 pub fn process_stash(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, miner_info, garage_info, treasury_info, board_info, ore_program] = accounts
+    let [signer_info, miner_info, garage_info, config_info, treasury_info, board_info, system_program, ore_program] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -27,10 +31,9 @@ pub fn process_stash(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     // Validate signer.
     signer_info.is_signer()?;
 
-    // Validate miner PDA.
+    // Validate miner (the signer's miner at any table).
     let miner = miner_info
         .is_writable()?
-        .has_seeds(&[MINER, &signer_info.key.to_bytes()], &speedway_api::ID)?
         .as_account_mut::<Miner>(&speedway_api::ID)?
         .assert_mut(|m| m.authority == *signer_info.key)?;
 
@@ -38,12 +41,23 @@ pub fn process_stash(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     if garage_info.data_is_empty() {
         return Err(OreError::GarageRequired.into());
     }
-    let garage = garage_info
+    garage_info
         .is_writable()?
-        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?
+        .has_seeds(&[GARAGE, &signer_info.key.to_bytes()], &speedway_api::ID)?;
+    migrate_garage(garage_info, signer_info)?;
+    let garage = garage_info
         .as_account_mut::<Garage>(&speedway_api::ID)?
         .assert_mut(|g| g.authority == *signer_info.key)?;
 
+    // Validate config.
+    let config = config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(
+            |c| !c.is_paused(PAUSE_CLAIM | PAUSE_GARAGE_DEPOSIT),
+            OreError::Paused.into(),
+        )?;
+
     // Validate treasury.
     let treasury = treasury_info
         .is_writable()?
@@ -52,14 +66,10 @@ pub fn process_stash(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     // Validate board (for logging).
     board_info.as_account::<Board>(&speedway_api::ID)?;
 
-    // Validate program.
+    // Validate programs.
+    system_program.is_program(&system_program::ID)?;
     ore_program.is_program(&speedway_api::ID)?;
 
-    // Check if garage is exhausted.
-    if garage.is_exhausted() {
-        return Err(OreError::GarageExhausted.into());
-    }
-
     // Update miner rewards before calculating available amount.
     miner.update_rewards(treasury);
 
@@ -83,15 +93,24 @@ pub fn process_stash(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     treasury.total_unclaimed = treasury.total_unclaimed.saturating_sub(rewards_ore);
     treasury.total_refined = treasury.total_refined.saturating_sub(refined_ore);
 
+    // Exhausted garages start a new cycle on deposit.
+    if garage.is_exhausted() {
+        let event = garage.start_new_cycle(clock.unix_timestamp);
+        emit_new_cycle(board_info, ore_program, event)?;
+    }
+
     // Update garage deposits (0% fee - full amount goes to deposits).
+    treasury.track_garage(garage)?;
+    let outstanding_before = garage.outstanding();
     garage.total_deposited = garage
         .total_deposited
         .checked_add(amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
     garage.last_action_at = clock.unix_timestamp;
-    garage.update_max_payout();
+    garage.update_max_payout(config.params.max_payout_mult);
 
-    // Update treasury total garage TVL.
+    // Update treasury Garage totals.
+    treasury.update_garage_outstanding(outstanding_before, garage.outstanding())?;
     treasury.total_garage_tvl = treasury
         .total_garage_tvl
         .checked_add(amount)
@@ -0,0 +1,36 @@
+use speedway_api::prelude::*;
+use steel::*;
+
+/// Transfers a referral code owned by the signer to another Garage owner.
+///
+/// Only future deposits using the code are credited to the new owner.
+pub fn process_transfer_referral_code(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, referral_code_info, new_authority_info, new_authority_garage_info] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let referral_code = referral_code_info
+        .is_writable()?
+        .as_account_mut::<ReferralCode>(&speedway_api::ID)?
+        .assert_mut_err(
+            |c| c.authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    referral_code_info.has_seeds(&[REFERRAL_CODE, &referral_code.code], &speedway_api::ID)?;
+
+    // New owner must have a Garage to receive referrals.
+    if new_authority_garage_info.data_is_empty() {
+        return Err(OreError::ReferrerNoGarage.into());
+    }
+    // The Garage is not loaded, so Garages predating the current layout are accepted.
+    new_authority_garage_info
+        .has_owner(&speedway_api::ID)?
+        .has_seeds(&[GARAGE, &new_authority_info.key.to_bytes()], &speedway_api::ID)?;
+
+    // Transfer referral code.
+    referral_code.authority = *new_authority_info.key;
+
+    Ok(())
+}
@@ -9,11 +9,18 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
     let amount = u64::from_le_bytes(args.amount);
 
     // Load accounts.
-    let [signer_info, _config_info, treasury_info, treasury_sol_info, system_program] = accounts
+    let [signer_info, config_info, treasury_info, treasury_sol_info, system_program] = accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
-    signer_info.is_signer()?.has_address(&BURY_AUTHORITY)?;
+    signer_info.is_signer()?;
+    config_info
+        .has_seeds(&[CONFIG], &speedway_api::ID)?
+        .as_account::<Config>(&speedway_api::ID)?
+        .assert_err(
+            |c| c.has_role(Role::TreasuryOperator, signer_info.key),
+            OreError::NotAuthorized.into(),
+        )?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&speedway_api::ID)?;
     treasury_sol_info
         .is_writable()?